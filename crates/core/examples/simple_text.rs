@@ -0,0 +1,32 @@
+use rusvid_core::holder::likes::types_like::TypesLike;
+use rusvid_core::holder::object::Object;
+use rusvid_core::holder::text_holder::{Font, TextAlign, TextHolder, TextOptions};
+use rusvid_core::holder::transform::{Transform, TransformLogic};
+use rusvid_core::point::Point;
+
+const SIZE: u32 = 500;
+
+fn main() {
+    let font = Font::from_file("crates/core/tests/data/fonts/DejaVuSansMono.ttf").unwrap();
+
+    let text = TextHolder::new_with_id(
+        "text",
+        Point::new(SIZE as f64 / 2.0, 150.0),
+        "Hello\nrusvid!",
+        &font,
+        TextOptions {
+            font_size: 80.0,
+            align: TextAlign::Center,
+            line_spacing: 1.2,
+            ..TextOptions::default()
+        },
+    );
+
+    let mut object = Object::new(TypesLike::Text(text));
+    object
+        .transform_by_id("text_word_1", &Transform::Rotate(-10_f64.to_radians()))
+        .unwrap();
+
+    let plane = object.render(SIZE, SIZE).unwrap();
+    plane.save_as_png("simple_text.png").unwrap();
+}
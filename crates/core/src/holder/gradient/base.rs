@@ -1,31 +1,91 @@
+use glam::{DAffine2, DVec2};
+
 use crate::holder::gradient::stop::Stop;
-use crate::holder::utils::TranslateIntoResvgGeneric;
+use crate::holder::utils::{transform_from_resvg, TranslateIntoResvgGeneric};
 use crate::pixel::Pixel;
+use crate::point::Point;
 
-#[derive(Debug, Clone, Default, PartialEq)]
+/// Coordinate system of the gradient points.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
-pub struct BaseGradient {
-    // TODO add transformation like 'rotation', ...
-    stops: Vec<Stop>,
+pub enum GradientUnits {
+    /// The points are relative to the bounding box of the item, `(0, 0)` is the top left and
+    /// `(1, 1)` the bottom right corner.
+    #[default]
+    ObjectBoundingBox,
+
+    /// The points are in the same coordinate system as the path of the item.
+    UserSpaceOnUse,
+}
+
+impl TranslateIntoResvgGeneric<resvg::usvg::Units> for GradientUnits {
+    fn translate(&self) -> resvg::usvg::Units {
+        match self {
+            GradientUnits::ObjectBoundingBox => resvg::usvg::Units::ObjectBoundingBox,
+            GradientUnits::UserSpaceOnUse => resvg::usvg::Units::UserSpaceOnUse,
+        }
+    }
 }
 
-impl TranslateIntoResvgGeneric<resvg::usvg::BaseGradient> for BaseGradient {
-    fn translate(&self) -> resvg::usvg::BaseGradient {
-        use resvg::usvg::{SpreadMethod, Transform, Units};
+/// How the area outside of the gradient is painted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub enum SpreadMethod {
+    /// The colors of the first and the last stop are extended.
+    #[default]
+    Pad,
 
-        resvg::usvg::BaseGradient {
-            units: Units::ObjectBoundingBox,
-            transform: Transform::default(),
-            spread_method: SpreadMethod::Pad,
-            stops: self.stops.iter().map(|s| s.translate()).collect(),
+    /// The gradient is repeated mirrored, e.g. red to blue, blue to red, ...
+    Reflect,
+
+    /// The gradient is repeated, e.g. red to blue, red to blue, ...
+    Repeat,
+}
+
+impl From<resvg::usvg::SpreadMethod> for SpreadMethod {
+    fn from(value: resvg::usvg::SpreadMethod) -> Self {
+        match value {
+            resvg::usvg::SpreadMethod::Pad => SpreadMethod::Pad,
+            resvg::usvg::SpreadMethod::Reflect => SpreadMethod::Reflect,
+            resvg::usvg::SpreadMethod::Repeat => SpreadMethod::Repeat,
         }
     }
 }
 
+impl TranslateIntoResvgGeneric<resvg::usvg::SpreadMethod> for SpreadMethod {
+    fn translate(&self) -> resvg::usvg::SpreadMethod {
+        match self {
+            SpreadMethod::Pad => resvg::usvg::SpreadMethod::Pad,
+            SpreadMethod::Reflect => resvg::usvg::SpreadMethod::Reflect,
+            SpreadMethod::Repeat => resvg::usvg::SpreadMethod::Repeat,
+        }
+    }
+}
+
+pub(crate) fn lerp(start: f64, end: f64, t: f64) -> f64 {
+    start + (end - start) * t
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct BaseGradient {
+    stops: Vec<Stop>,
+
+    units: GradientUnits,
+    spread_method: SpreadMethod,
+    transform: DAffine2,
+    rotation: f64,
+}
+
 impl BaseGradient {
     pub fn new(stops: Vec<Stop>) -> Self {
-        BaseGradient { stops }
+        BaseGradient {
+            stops,
+            ..Default::default()
+        }
     }
 
     pub fn new_from_colors(colors: Vec<Pixel>) -> Self {
@@ -40,4 +100,288 @@ impl BaseGradient {
             .collect();
         Self::new(stops)
     }
+
+    pub fn from_resvg(gradient: &resvg::usvg::BaseGradient) -> Self {
+        let units = match gradient.units {
+            resvg::usvg::Units::ObjectBoundingBox => GradientUnits::ObjectBoundingBox,
+            resvg::usvg::Units::UserSpaceOnUse => GradientUnits::UserSpaceOnUse,
+        };
+
+        BaseGradient {
+            stops: gradient.stops.iter().map(Stop::from_resvg).collect(),
+            units,
+            spread_method: gradient.spread_method.into(),
+            transform: transform_from_resvg(&gradient.transform),
+            rotation: 0.0,
+        }
+    }
+
+    pub fn stops(&self) -> &[Stop] {
+        &self.stops
+    }
+
+    pub fn stops_mut(&mut self) -> &mut Vec<Stop> {
+        &mut self.stops
+    }
+
+    pub fn set_stops(&mut self, stops: Vec<Stop>) {
+        self.stops = stops;
+    }
+
+    /// Adds `offset` to the offset of every stop, the offsets are clamped between `0.0` and
+    /// `1.0`. Use [`SpreadMethod::Repeat`] and move the points of the gradient for a repeating
+    /// motion.
+    pub fn shift_stops(&mut self, offset: f64) {
+        self.stops
+            .iter_mut()
+            .for_each(|stop| stop.set_offset(stop.offset() + offset));
+    }
+
+    pub fn units(&self) -> GradientUnits {
+        self.units
+    }
+
+    /// Sets the coordinate system of the points, the points of the gradient aren't converted.
+    pub fn set_units(&mut self, units: GradientUnits) {
+        self.units = units;
+    }
+
+    pub fn spread_method(&self) -> SpreadMethod {
+        self.spread_method
+    }
+
+    pub fn set_spread_method(&mut self, spread_method: SpreadMethod) {
+        self.spread_method = spread_method;
+    }
+
+    /// The `gradientTransform`, it's applied after the rotation.
+    pub fn transform(&self) -> DAffine2 {
+        self.transform
+    }
+
+    pub fn set_transform(&mut self, transform: DAffine2) {
+        self.transform = transform;
+    }
+
+    /// Rotation in radiant around the center of the gradient, a positive angle rotates
+    /// counterclockwise.
+    pub fn rotation(&self) -> f64 {
+        self.rotation
+    }
+
+    /// Sets the rotation, the value is absolute and doesn't accumulate.
+    pub fn set_rotation(&mut self, angle: f64) {
+        self.rotation = angle;
+    }
+
+    /// Color of the gradient at `offset`, between two stops the colors are interpolated and
+    /// outside of the stops the color of the nearest stop is used.
+    pub fn color_at(&self, offset: f64) -> Pixel {
+        let stops = &self.stops;
+
+        match stops.iter().position(|stop| stop.offset >= offset) {
+            None => stops.last().map(Stop::color).unwrap_or(Pixel::ZERO),
+            Some(0) => stops[0].color,
+            Some(i) => {
+                let (start, end) = (stops[i - 1], stops[i]);
+                let range = end.offset - start.offset;
+                let t = match range > 0.0 {
+                    true => (offset - start.offset) / range,
+                    false => 1.0,
+                };
+
+                start.color.interpolate(&end.color, t)
+            }
+        }
+    }
+
+    /// Interpolates between two gradients, `0.0` returns `self` and `1.0` returns `other`.
+    ///
+    /// If both have the same number of stops, the colors and offsets of the stops are
+    /// interpolated pairwise. Otherwise both gradients are sampled at the offsets of all stops.
+    /// The units and the spread method can't be interpolated, they switch at `0.5`.
+    pub fn interpolate(&self, other: &BaseGradient, t: f64) -> BaseGradient {
+        let stops = match self.stops.len() == other.stops.len() {
+            true => self
+                .stops
+                .iter()
+                .zip(other.stops.iter())
+                .map(|(start, end)| {
+                    Stop::new(
+                        start.color.interpolate(&end.color, t),
+                        lerp(start.offset, end.offset, t),
+                    )
+                })
+                .collect(),
+            false => {
+                let mut offsets: Vec<f64> = self
+                    .stops
+                    .iter()
+                    .chain(other.stops.iter())
+                    .map(Stop::offset)
+                    .collect();
+                offsets.sort_by(f64::total_cmp);
+                offsets.dedup();
+
+                offsets
+                    .into_iter()
+                    .map(|offset| {
+                        let color = self.color_at(offset);
+                        Stop::new(color.interpolate(&other.color_at(offset), t), offset)
+                    })
+                    .collect()
+            }
+        };
+
+        let (units, spread_method) = match t < 0.5 {
+            true => (self.units, self.spread_method),
+            false => (other.units, other.spread_method),
+        };
+
+        let start = self.transform.to_cols_array();
+        let end = other.transform.to_cols_array();
+        let mut transform = [0.0; 6];
+        for i in 0..6 {
+            transform[i] = lerp(start[i], end[i], t);
+        }
+
+        BaseGradient {
+            stops,
+            units,
+            spread_method,
+            transform: DAffine2::from_cols_array(&transform),
+            rotation: lerp(self.rotation, other.rotation, t),
+        }
+    }
+
+    /// The `gradientTransform` with the rotation around `center` applied.
+    fn matrix(&self, center: Point) -> DAffine2 {
+        let center = DVec2::from(center);
+
+        // the y axis points downwards, therefore the angle is negated to rotate counterclockwise
+        self.transform
+            * DAffine2::from_translation(center)
+            * DAffine2::from_angle(-self.rotation)
+            * DAffine2::from_translation(-center)
+    }
+
+    /// Converts the gradient into resvg, `center` is the center of the rotation in the
+    /// coordinate system of the points.
+    pub(crate) fn translate_with_center(&self, center: Point) -> resvg::usvg::BaseGradient {
+        resvg::usvg::BaseGradient {
+            units: self.units.translate(),
+            transform: self.matrix(center).translate(),
+            spread_method: self.spread_method.translate(),
+            stops: self.stops.iter().map(|s| s.translate()).collect(),
+        }
+    }
+
+    /// Multiplies the alpha channel of every stop with `opacity`.
+    pub(crate) fn multiply_opacity(&mut self, opacity: f64) {
+        self.stops
+            .iter_mut()
+            .for_each(|stop| stop.multiply_opacity(opacity));
+    }
+
+    /// Moves the gradient into the coordinate system given by `transform`.
+    ///
+    /// `bounding_box` must be the bounding box of the item before the transformation, it's used
+    /// to convert [`GradientUnits::ObjectBoundingBox`] into [`GradientUnits::UserSpaceOnUse`].
+    pub(crate) fn bake_transform(&mut self, transform: DAffine2, bounding_box: (Point, Point)) {
+        if self.units == GradientUnits::ObjectBoundingBox {
+            let size = bounding_box.1 - bounding_box.0;
+            let bounding_box_transform = DAffine2::from_translation(bounding_box.0.into())
+                * DAffine2::from_scale(size.into());
+
+            self.transform = bounding_box_transform * self.transform;
+            self.units = GradientUnits::UserSpaceOnUse;
+        }
+
+        self.transform = transform * self.transform;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+    use crate::holder::gradient::linear::LinearGradient;
+
+    fn gradient() -> LinearGradient {
+        LinearGradient::new(BaseGradient::new_from_colors(vec![
+            Pixel::new(255, 0, 0, 255),
+            Pixel::new(0, 0, 255, 255),
+        ]))
+    }
+
+    #[test]
+    fn spread_method_round_trip() {
+        for spread_method in [
+            SpreadMethod::Pad,
+            SpreadMethod::Reflect,
+            SpreadMethod::Repeat,
+        ] {
+            let mut gradient = gradient();
+            gradient.base_mut().set_spread_method(spread_method);
+
+            let translated: resvg::usvg::LinearGradient = gradient.translate();
+            assert_eq!(
+                LinearGradient::from_resvg(&translated)
+                    .base()
+                    .spread_method(),
+                spread_method
+            );
+        }
+    }
+
+    #[test]
+    fn rotates_around_the_center() {
+        let mut gradient = gradient();
+        gradient
+            .base_mut()
+            .set_rotation(std::f64::consts::FRAC_PI_2);
+
+        let translated: resvg::usvg::LinearGradient = gradient.translate();
+        let matrix = transform_from_resvg(&translated.base.transform);
+
+        // from left to right becomes from bottom to top
+        assert_abs_diff_eq!(
+            Point::from(matrix.transform_point2(DVec2::new(0.0, 0.5))),
+            Point::new(0.5, 1.0),
+            epsilon = 0.0001
+        );
+        assert_abs_diff_eq!(
+            Point::from(matrix.transform_point2(DVec2::new(1.0, 0.5))),
+            Point::new(0.5, 0.0),
+            epsilon = 0.0001
+        );
+    }
+
+    #[test]
+    fn rotation_is_applied_before_the_transform() {
+        let mut gradient = gradient();
+        gradient
+            .base_mut()
+            .set_transform(DAffine2::from_translation(DVec2::new(10.0, 0.0)));
+        gradient.base_mut().set_rotation(std::f64::consts::PI);
+
+        let translated: resvg::usvg::LinearGradient = gradient.translate();
+        let matrix = transform_from_resvg(&translated.base.transform);
+
+        assert_abs_diff_eq!(
+            Point::from(matrix.transform_point2(DVec2::new(0.0, 0.5))),
+            Point::new(11.0, 0.5),
+            epsilon = 0.0001
+        );
+    }
+
+    #[test]
+    fn shift_stops_clamps_the_offsets() {
+        let mut gradient = gradient();
+        gradient.base_mut().shift_stops(0.25);
+
+        let offsets: Vec<f64> = gradient.base().stops().iter().map(Stop::offset).collect();
+        assert_eq!(offsets, vec![0.25, 1.0]);
+    }
 }
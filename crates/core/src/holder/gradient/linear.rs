@@ -15,13 +15,59 @@ pub struct LinearGradient {
 }
 
 impl LinearGradient {
+    /// Creates a gradient from left to right through the middle of the bounding box.
     pub fn new(base: BaseGradient) -> LinearGradient {
+        Self::new_with_points(Point::new(0.0, 0.5), Point::new(1.0, 0.5), base)
+    }
+
+    /// Creates a gradient from `point_1` to `point_2`, the points are in the coordinate system of
+    /// [`BaseGradient::units`].
+    pub fn new_with_points(point_1: Point, point_2: Point, base: BaseGradient) -> LinearGradient {
         LinearGradient {
-            point_1: Point::ZERO,
-            point_2: Point::new(1.0, 0.0),
+            point_1,
+            point_2,
             base,
         }
     }
+
+    pub fn from_resvg(gradient: &resvg::usvg::LinearGradient) -> Self {
+        LinearGradient {
+            point_1: Point::new(gradient.x1, gradient.y1),
+            point_2: Point::new(gradient.x2, gradient.y2),
+            base: BaseGradient::from_resvg(&gradient.base),
+        }
+    }
+
+    pub fn points(&self) -> (Point, Point) {
+        (self.point_1, self.point_2)
+    }
+
+    pub fn set_points(&mut self, point_1: Point, point_2: Point) {
+        self.point_1 = point_1;
+        self.point_2 = point_2;
+    }
+
+    /// The center of the rotation, the middle between both points.
+    pub fn center(&self) -> Point {
+        (self.point_1 + self.point_2) / 2.0
+    }
+
+    /// Interpolates the points and the base, see [`BaseGradient::interpolate`].
+    pub fn interpolate(&self, other: &LinearGradient, t: f64) -> LinearGradient {
+        LinearGradient {
+            point_1: self.point_1 + (other.point_1 - self.point_1) * t,
+            point_2: self.point_2 + (other.point_2 - self.point_2) * t,
+            base: self.base.interpolate(&other.base, t),
+        }
+    }
+
+    pub fn base(&self) -> &BaseGradient {
+        &self.base
+    }
+
+    pub fn base_mut(&mut self) -> &mut BaseGradient {
+        &mut self.base
+    }
 }
 
 impl TranslateIntoResvgGeneric<resvg::usvg::LinearGradient> for LinearGradient {
@@ -32,7 +78,7 @@ impl TranslateIntoResvgGeneric<resvg::usvg::LinearGradient> for LinearGradient {
             y1: self.point_1.y(),
             x2: self.point_2.x(),
             y2: self.point_2.y(),
-            base: self.base.translate(),
+            base: self.base.translate_with_center(self.center()),
         }
     }
 }
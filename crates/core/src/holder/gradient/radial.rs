@@ -1,6 +1,6 @@
 use std::rc::Rc;
 
-use crate::holder::gradient::base::BaseGradient;
+use crate::holder::gradient::base::{lerp, BaseGradient};
 use crate::holder::utils::TranslateIntoResvgGeneric;
 use crate::point::Point;
 
@@ -29,6 +29,60 @@ impl RadialGradient {
             base,
         }
     }
+
+    pub fn from_resvg(gradient: &resvg::usvg::RadialGradient) -> Self {
+        RadialGradient {
+            end_circle: Point::new(gradient.cx, gradient.cy),
+            start_circle_radius: gradient.r.get(),
+            start_circle: Point::new(gradient.fx, gradient.fy),
+            base: BaseGradient::from_resvg(&gradient.base),
+        }
+    }
+
+    /// Center of the end circle and of the rotation.
+    pub fn center(&self) -> Point {
+        self.end_circle
+    }
+
+    pub fn set_center(&mut self, center: Point) {
+        self.end_circle = center;
+    }
+
+    pub fn radius(&self) -> f64 {
+        self.start_circle_radius
+    }
+
+    /// Sets the radius, negative values are made positive.
+    pub fn set_radius(&mut self, radius: f64) {
+        self.start_circle_radius = radius.abs();
+    }
+
+    /// Focal point where the gradient starts.
+    pub fn focal(&self) -> Point {
+        self.start_circle
+    }
+
+    pub fn set_focal(&mut self, focal: Point) {
+        self.start_circle = focal;
+    }
+
+    /// Interpolates the circles and the base, see [`BaseGradient::interpolate`].
+    pub fn interpolate(&self, other: &RadialGradient, t: f64) -> RadialGradient {
+        RadialGradient {
+            end_circle: self.end_circle + (other.end_circle - self.end_circle) * t,
+            start_circle_radius: lerp(self.start_circle_radius, other.start_circle_radius, t),
+            start_circle: self.start_circle + (other.start_circle - self.start_circle) * t,
+            base: self.base.interpolate(&other.base, t),
+        }
+    }
+
+    pub fn base(&self) -> &BaseGradient {
+        &self.base
+    }
+
+    pub fn base_mut(&mut self) -> &mut BaseGradient {
+        &mut self.base
+    }
 }
 
 impl TranslateIntoResvgGeneric<resvg::usvg::RadialGradient> for RadialGradient {
@@ -40,7 +94,7 @@ impl TranslateIntoResvgGeneric<resvg::usvg::RadialGradient> for RadialGradient {
             r: resvg::usvg::PositiveF64::new(self.start_circle_radius.abs()).unwrap(),
             fx: self.start_circle.x(),
             fy: self.start_circle.y(),
-            base: self.base.translate(),
+            base: self.base.translate_with_center(self.center()),
         }
     }
 }
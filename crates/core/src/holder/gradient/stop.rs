@@ -14,6 +14,39 @@ impl Stop {
     pub fn new(color: Pixel, offset: f64) -> Self {
         Stop { color, offset }
     }
+
+    pub fn from_resvg(stop: &resvg::usvg::Stop) -> Self {
+        let color = Pixel::new(
+            stop.color.red,
+            stop.color.green,
+            stop.color.blue,
+            (stop.opacity.get() * 255.0).round() as u8,
+        );
+
+        Stop::new(color, stop.offset.get())
+    }
+
+    pub fn offset(&self) -> f64 {
+        self.offset
+    }
+
+    /// Sets the offset, the value is clamped between `0.0` and `1.0`.
+    pub fn set_offset(&mut self, offset: f64) {
+        self.offset = offset.clamp(0.0, 1.0);
+    }
+
+    pub fn color(&self) -> Pixel {
+        self.color
+    }
+
+    pub fn set_color(&mut self, color: Pixel) {
+        self.color = color;
+    }
+
+    pub(crate) fn multiply_opacity(&mut self, opacity: f64) {
+        let alpha = (self.color[3] as f64) * opacity.clamp(0.0, 1.0);
+        self.color[3] = alpha.round() as u8;
+    }
 }
 
 impl TranslateIntoResvgGeneric<resvg::usvg::Stop> for Stop {
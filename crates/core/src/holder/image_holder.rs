@@ -1,21 +1,33 @@
-use crate::plane::Plane;
+use std::path::Path;
+use std::sync::Arc;
+
+use glam::DAffine2;
+use resvg::tiny_skia::{FilterQuality, Paint, Pattern, Pixmap, PixmapPaint, Rect, SpreadMode};
+use resvg::usvg::{
+    Align, AspectRatio, Group, Image, ImageKind, ImageRendering, Node, NodeExt, NodeKind, Opacity,
+    ViewBox, Visibility,
+};
+
+use crate::holder::transform::{Transform, TransformError, TransformLogic, TransformStack};
+use crate::holder::utils::{transform_bounding_box, TranslateIntoResvgGeneric};
+use crate::plane::{Plane, PlaneError, PlaneResult, SIZE};
 use crate::point::Point;
 
-#[derive(Debug, PartialEq)]
+/// How the image is placed into the box `coordinates` to `coordinates + size` of the
+/// [`ImageHolder`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
 pub enum ImageMode {
-    // TODO's
-    // Crop,
-    // Scale,
-    // Repeat,
+    /// Scales the image uniformly so that it fits completely into the box, the image is centered
+    #[default]
     Fit,
-}
-
-impl Default for ImageMode {
-    fn default() -> Self {
-        ImageMode::Fit
-    }
+    /// Keeps the size of the image, the parts outside of the box are cut off
+    Crop,
+    /// Scales the image to the size of the box, the aspect ratio isn't kept
+    Scale,
+    /// Repeats the image with its original size to fill the box
+    Repeat,
 }
 
 #[derive(Debug)]
@@ -26,6 +38,10 @@ pub struct ImageHolder {
     pub(crate) size: Point,
     pub(crate) data: Plane,
     pub(crate) mode: ImageMode,
+
+    pub(crate) visibility: bool,
+    pub(crate) opacity: f64,
+    pub(crate) transform: TransformStack,
 }
 
 impl ImageHolder {
@@ -35,6 +51,9 @@ impl ImageHolder {
             size,
             data,
             mode,
+            visibility: true,
+            opacity: 1.0,
+            transform: TransformStack::default(),
         }
     }
 
@@ -44,4 +63,470 @@ impl ImageHolder {
 
         ImageHolder::new_unchecked(coordinates, size, data, mode)
     }
+
+    /// Creates an [`ImageHolder`] with the box `coordinates` to `coordinates + size`, returns an
+    /// error if the size isn't greater than zero.
+    pub fn new(coordinates: Point, size: Point, data: Plane, mode: ImageMode) -> PlaneResult<Self> {
+        if size.x() <= 0.0 || size.y() <= 0.0 {
+            return Err(PlaneError::ValueGreaterZero("size"));
+        }
+
+        Ok(ImageHolder::new_unchecked(coordinates, size, data, mode))
+    }
+
+    /// Loads a png, jpeg or bmp file, the box has the size of the image.
+    pub fn from_file(coordinates: Point, path: impl AsRef<Path>) -> PlaneResult<Self> {
+        let image = image::open(path)?;
+        let data = Plane::from_rgba_image(image.to_rgba8())?;
+
+        Ok(ImageHolder::new_fit(coordinates, data))
+    }
+
+    pub fn coordinates(&self) -> Point {
+        self.coordinates
+    }
+
+    pub fn size(&self) -> Point {
+        self.size
+    }
+
+    pub fn set_size(&mut self, size: Point) {
+        self.size = size;
+    }
+
+    pub fn data(&self) -> &Plane {
+        &self.data
+    }
+
+    /// Replaces the image, the box and the transformations are kept.
+    pub fn set_data(&mut self, data: Plane) {
+        self.data = data;
+    }
+
+    pub fn mode(&self) -> ImageMode {
+        self.mode
+    }
+
+    pub fn set_mode(&mut self, mode: ImageMode) {
+        self.mode = mode;
+    }
+
+    pub fn visibility(&self) -> bool {
+        self.visibility
+    }
+
+    pub fn opacity(&self) -> f64 {
+        self.opacity
+    }
+
+    pub fn transform_stack(&self) -> &TransformStack {
+        &self.transform
+    }
+
+    /// Combines all transformations of the image into one matrix, the default origin is the
+    /// center of the box.
+    pub fn transform_matrix(&self) -> DAffine2 {
+        let center = self.coordinates + self.size / 2.0;
+        let center = self.transform.matrix.transform_point2(center.into());
+
+        self.transform.to_matrix(center.into())
+    }
+
+    /// Bounding box of the box with all transformations applied.
+    pub fn bounding_box(&self) -> (Point, Point) {
+        transform_bounding_box(
+            &self.transform_matrix(),
+            (self.coordinates, self.coordinates + self.size),
+        )
+    }
+
+    fn box_size(&self) -> (SIZE, SIZE) {
+        (
+            (self.size.x().round() as SIZE).max(1),
+            (self.size.y().round() as SIZE).max(1),
+        )
+    }
+
+    /// Renders the image with the [`ImageMode`] into a plane with the size of the box, the
+    /// transformations aren't applied.
+    pub fn render_box(&self) -> PlaneResult<Plane> {
+        Ok(Plane::from_pixmap(self.render_box_pixmap()?))
+    }
+
+    fn render_box_pixmap(&self) -> PlaneResult<Pixmap> {
+        let (width, height) = self.box_size();
+        let mut pixmap = Pixmap::new(width, height).ok_or(PlaneError::TinySkiaError)?;
+
+        let image = self.data.to_pixmap()?;
+        let image_width = image.width() as f32;
+        let image_height = image.height() as f32;
+        let box_width = width as f32;
+        let box_height = height as f32;
+
+        let (rect, spread_mode, transform) = match self.mode {
+            ImageMode::Fit => {
+                let scale = (box_width / image_width).min(box_height / image_height);
+                let offset_x = (box_width - image_width * scale) / 2.0;
+                let offset_y = (box_height - image_height * scale) / 2.0;
+
+                (
+                    Rect::from_xywh(
+                        offset_x,
+                        offset_y,
+                        image_width * scale,
+                        image_height * scale,
+                    ),
+                    SpreadMode::Pad,
+                    resvg::tiny_skia::Transform::from_row(
+                        scale, 0.0, 0.0, scale, offset_x, offset_y,
+                    ),
+                )
+            }
+            ImageMode::Crop => (
+                Rect::from_xywh(
+                    0.0,
+                    0.0,
+                    image_width.min(box_width),
+                    image_height.min(box_height),
+                ),
+                SpreadMode::Pad,
+                resvg::tiny_skia::Transform::identity(),
+            ),
+            ImageMode::Scale => (
+                Rect::from_xywh(0.0, 0.0, box_width, box_height),
+                SpreadMode::Pad,
+                resvg::tiny_skia::Transform::from_scale(
+                    box_width / image_width,
+                    box_height / image_height,
+                ),
+            ),
+            ImageMode::Repeat => (
+                Rect::from_xywh(0.0, 0.0, box_width, box_height),
+                SpreadMode::Repeat,
+                resvg::tiny_skia::Transform::identity(),
+            ),
+        };
+
+        let rect = rect.ok_or(PlaneError::TinySkiaError)?;
+        let paint = Paint {
+            shader: Pattern::new(
+                image.as_ref(),
+                spread_mode,
+                filter_quality(&transform),
+                1.0,
+                transform,
+            ),
+            ..Paint::default()
+        };
+        pixmap.fill_rect(rect, &paint, resvg::tiny_skia::Transform::identity(), None);
+
+        Ok(pixmap)
+    }
+
+    pub fn render(&self, width: SIZE, height: SIZE) -> PlaneResult<Plane> {
+        self.render_with_opacity(width, height, 1.0)
+    }
+
+    /// Renders the image with an additional `opacity`, e.g. the opacity of the
+    /// [`crate::holder::object::Object`].
+    pub(crate) fn render_with_opacity(
+        &self,
+        width: SIZE,
+        height: SIZE,
+        opacity: f64,
+    ) -> PlaneResult<Plane> {
+        let mut pixmap = Pixmap::new(width, height).ok_or(PlaneError::TinySkiaError)?;
+
+        if self.visibility {
+            let image = self.render_box_pixmap()?;
+
+            let transform = (self.transform_matrix()
+                * DAffine2::from_translation(self.coordinates.into()))
+            .translate();
+            let transform = resvg::tiny_skia::Transform::from_row(
+                transform.a as f32,
+                transform.b as f32,
+                transform.c as f32,
+                transform.d as f32,
+                transform.e as f32,
+                transform.f as f32,
+            );
+
+            pixmap.draw_pixmap(
+                0,
+                0,
+                image.as_ref(),
+                &PixmapPaint {
+                    opacity: (self.opacity * opacity) as f32,
+                    quality: filter_quality(&transform),
+                    ..PixmapPaint::default()
+                },
+                transform,
+                None,
+            );
+        }
+
+        Ok(Plane::from_pixmap(pixmap))
+    }
+
+    /// Appends the image as png with all transformations to `node`, an image with an opacity is
+    /// wrapped in a group because an image has no opacity on its own.
+    pub fn append_to_resvg_node(&self, node: &Node) -> PlaneResult<()> {
+        // an image without an area isn't visible
+        let Some(rect) = resvg::usvg::Rect::new(
+            self.coordinates.x(),
+            self.coordinates.y(),
+            self.size.x(),
+            self.size.y(),
+        ) else {
+            return Ok(());
+        };
+
+        let mut data = Vec::new();
+        self.render_box()?.writer_as_png(&mut data)?;
+
+        let visibility = match self.visibility {
+            true => Visibility::Visible,
+            false => Visibility::Hidden,
+        };
+
+        let image = NodeKind::Image(Image {
+            id: String::new(),
+            transform: self.transform_matrix().translate(),
+            visibility,
+            view_box: ViewBox {
+                rect,
+                // the image mode is already applied by `render_box`
+                aspect: AspectRatio {
+                    align: Align::None,
+                    ..AspectRatio::default()
+                },
+            },
+            rendering_mode: ImageRendering::default(),
+            kind: ImageKind::PNG(Arc::new(data)),
+        });
+
+        if self.opacity < 1.0 {
+            node.append_kind(NodeKind::Group(Group {
+                opacity: Opacity::new_clamped(self.opacity),
+                ..Group::default()
+            }))
+            .append_kind(image);
+        } else {
+            node.append_kind(image);
+        }
+
+        Ok(())
+    }
+}
+
+/// Pixels are only interpolated if the transformation doesn't map them exactly onto other pixels.
+fn filter_quality(transform: &resvg::tiny_skia::Transform) -> FilterQuality {
+    let is_integer = |value: f32| value.fract() == 0.0;
+
+    match transform.is_scale_translate()
+        && transform.sx == 1.0
+        && transform.sy == 1.0
+        && is_integer(transform.tx)
+        && is_integer(transform.ty)
+    {
+        true => FilterQuality::Nearest,
+        false => FilterQuality::Bilinear,
+    }
+}
+
+impl TransformLogic for ImageHolder {
+    fn transform(&mut self, transformation: &Transform) -> Result<(), TransformError> {
+        match transformation {
+            Transform::Visibility(value) => self.visibility = *value,
+            Transform::Move(point) => self.transform.translation += *point,
+            Transform::Position(position) => {
+                let current = self
+                    .transform_matrix()
+                    .transform_point2(self.coordinates.into());
+                self.transform.translation += *position - Point::from(current);
+            }
+            Transform::Scale(factor) => self.transform.scale = *factor,
+            Transform::Rotate(angle) => self.transform.rotation = *angle,
+            Transform::Skew(angles) => self.transform.skew = *angles,
+            Transform::Origin(origin) => self.transform.origin = *origin,
+            Transform::Matrix(matrix) => self.transform.matrix = *matrix,
+            Transform::Opacity(opacity) => self.opacity = opacity.clamp(0.0, 1.0),
+            Transform::Color(_)
+            | Transform::Stroke(_)
+            | Transform::Trim(_, _)
+            | Transform::Path(_) => {
+                return Err(TransformError::NotImplemented(
+                    "Color, Stroke, Trim and Path",
+                    "ImageHolder".to_string(),
+                ))
+            }
+        }
+
+        Ok(())
+    }
+
+    /// An image has no items, use [`TransformLogic::transform`] or the id of the
+    /// [`crate::holder::object::Object`] instead.
+    fn transform_by_id(
+        &mut self,
+        _id: impl Into<String>,
+        _transformation: &Transform,
+    ) -> Result<(), TransformError> {
+        Err(TransformError::NotImplemented(
+            "transform_by_id",
+            "ImageHolder".to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+    use crate::pixel::Pixel;
+
+    const RED: Pixel = Pixel::new_raw([255, 0, 0, 255]);
+    const BLUE: Pixel = Pixel::new_raw([0, 0, 255, 255]);
+    const TRANSPARENT: Pixel = Pixel::ZERO;
+
+    // 2x1 image with a red and a blue pixel
+    fn image(coordinates: Point, size: Point, mode: ImageMode) -> ImageHolder {
+        let mut data = Plane::new(2, 1).unwrap();
+        data.put_pixel(0, 0, RED).unwrap();
+        data.put_pixel(1, 0, BLUE).unwrap();
+
+        ImageHolder::new(coordinates, size, data, mode).unwrap()
+    }
+
+    fn row(plane: &Plane, y: SIZE) -> Vec<Pixel> {
+        (0..plane.width())
+            .map(|x| *plane.pixel(x, y).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn new_needs_a_size() {
+        let result = ImageHolder::new(
+            Point::ZERO,
+            Point::new(0.0, 1.0),
+            Plane::new(1, 1).unwrap(),
+            ImageMode::Fit,
+        );
+
+        assert_eq!(result.unwrap_err(), PlaneError::ValueGreaterZero("size"));
+    }
+
+    #[test]
+    fn renders_at_coordinates() {
+        let plane = image(Point::new(1.0, 0.0), Point::new(2.0, 1.0), ImageMode::Fit)
+            .render(4, 1)
+            .unwrap();
+
+        assert_eq!(row(&plane, 0), vec![TRANSPARENT, RED, BLUE, TRANSPARENT]);
+    }
+
+    #[test]
+    fn renders_negative_coordinates() {
+        let plane = image(Point::new(-1.0, 0.0), Point::new(2.0, 1.0), ImageMode::Fit)
+            .render(2, 1)
+            .unwrap();
+
+        assert_eq!(row(&plane, 0), vec![BLUE, TRANSPARENT]);
+    }
+
+    #[test]
+    fn modes() {
+        let render_box = |mode| {
+            let plane = image(Point::ZERO, Point::new(4.0, 2.0), mode)
+                .render_box()
+                .unwrap();
+            (row(&plane, 0), row(&plane, 1))
+        };
+
+        let (first, second) = render_box(ImageMode::Crop);
+        assert_eq!(first, vec![RED, BLUE, TRANSPARENT, TRANSPARENT]);
+        assert_eq!(second, vec![TRANSPARENT; 4]);
+
+        let (first, second) = render_box(ImageMode::Repeat);
+        assert_eq!(first, vec![RED, BLUE, RED, BLUE]);
+        assert_eq!(second, vec![RED, BLUE, RED, BLUE]);
+
+        // scaled images are interpolated between the pixels
+        let (first, second) = render_box(ImageMode::Fit);
+        assert_eq!((first[0], first[3]), (RED, BLUE));
+        assert_eq!(first, second);
+
+        let plane = image(Point::ZERO, Point::new(4.0, 4.0), ImageMode::Fit)
+            .render_box()
+            .unwrap();
+        assert_eq!(row(&plane, 0), vec![TRANSPARENT; 4]);
+        assert_eq!(row(&plane, 1)[0], RED);
+        assert_eq!(row(&plane, 2)[3], BLUE);
+
+        let plane = image(Point::ZERO, Point::new(4.0, 4.0), ImageMode::Scale)
+            .render_box()
+            .unwrap();
+        assert_eq!(row(&plane, 0)[0], RED);
+        assert_eq!(row(&plane, 3)[3], BLUE);
+    }
+
+    #[test]
+    fn visibility() {
+        let mut image = image(Point::ZERO, Point::new(2.0, 1.0), ImageMode::Fit);
+        image.transform(&Transform::Visibility(false)).unwrap();
+
+        let plane = image.render(2, 1).unwrap();
+
+        assert_eq!(row(&plane, 0), vec![TRANSPARENT; 2]);
+    }
+
+    #[test]
+    fn move_and_position() {
+        let mut image = image(Point::ZERO, Point::new(2.0, 1.0), ImageMode::Fit);
+
+        image
+            .transform(&Transform::Move(Point::new(1.0, 0.0)))
+            .unwrap();
+        assert_eq!(
+            row(&image.render(4, 1).unwrap(), 0),
+            vec![TRANSPARENT, RED, BLUE, TRANSPARENT]
+        );
+
+        image
+            .transform(&Transform::Position(Point::new(2.0, 0.0)))
+            .unwrap();
+        assert_eq!(
+            row(&image.render(4, 1).unwrap(), 0),
+            vec![TRANSPARENT, TRANSPARENT, RED, BLUE]
+        );
+    }
+
+    #[test]
+    fn rotate_and_scale_around_the_center() {
+        let mut image = image(Point::ZERO, Point::new(4.0, 2.0), ImageMode::Fit);
+
+        image
+            .transform(&Transform::Rotate(90_f64.to_radians()))
+            .unwrap();
+        let bounding_box = image.bounding_box();
+        assert_abs_diff_eq!(bounding_box.0, Point::new(1.0, -1.0), epsilon = 0.0001);
+        assert_abs_diff_eq!(bounding_box.1, Point::new(3.0, 3.0), epsilon = 0.0001);
+
+        image.transform(&Transform::Rotate(0.0)).unwrap();
+        image
+            .transform(&Transform::Scale(Point::new(2.0, 2.0)))
+            .unwrap();
+        let bounding_box = image.bounding_box();
+        assert_abs_diff_eq!(bounding_box.0, Point::new(-2.0, -1.0), epsilon = 0.0001);
+        assert_abs_diff_eq!(bounding_box.1, Point::new(6.0, 3.0), epsilon = 0.0001);
+    }
+
+    #[test]
+    fn color_isnt_supported() {
+        let mut image = image(Point::ZERO, Point::new(2.0, 1.0), ImageMode::Fit);
+
+        assert!(image.transform(&Transform::Color(None)).is_err());
+    }
 }
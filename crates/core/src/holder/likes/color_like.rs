@@ -1,9 +1,13 @@
+use glam::DAffine2;
+
 use crate::holder::gradient::linear::LinearGradient;
 use crate::holder::gradient::radial::RadialGradient;
+use crate::holder::gradient::stop::Stop;
 use crate::holder::utils::TranslateIntoResvgGeneric;
 use crate::pixel::Pixel;
+use crate::point::Point;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
 pub enum ColorLike {
@@ -13,12 +17,93 @@ pub enum ColorLike {
 }
 
 impl ColorLike {
-    pub fn from_resvg_paint(paint: &resvg::usvg::Paint) -> Self {
+    /// Converts a resvg paint, returns `None` for patterns because they aren't supported.
+    pub fn from_resvg_paint(paint: &resvg::usvg::Paint) -> Option<Self> {
         use resvg::usvg::Paint;
 
         match paint {
-            Paint::Color(c) => ColorLike::Color(Pixel::new(c.red, c.green, c.blue, 255)),
-            _ => todo!(),
+            Paint::Color(c) => Some(ColorLike::Color(Pixel::new(c.red, c.green, c.blue, 255))),
+            Paint::LinearGradient(l_g) => {
+                Some(ColorLike::LinearGradient(LinearGradient::from_resvg(l_g)))
+            }
+            Paint::RadialGradient(r_g) => {
+                Some(ColorLike::RadialGradient(RadialGradient::from_resvg(r_g)))
+            }
+            Paint::Pattern(_) => None,
+        }
+    }
+
+    /// Interpolates between two colors or gradients, `0.0` returns `self` and `1.0` returns
+    /// `other`.
+    ///
+    /// A solid color is promoted to a gradient with one stop and the geometry of the other
+    /// gradient, see [`LinearGradient::interpolate`] and [`RadialGradient::interpolate`]. A linear
+    /// and a radial gradient can't be interpolated, they switch at `0.5`.
+    pub fn interpolate(&self, other: &ColorLike, t: f64) -> ColorLike {
+        use ColorLike::*;
+
+        match (self, other) {
+            (Color(start), Color(end)) => Color(start.interpolate(end, t)),
+            (LinearGradient(start), LinearGradient(end)) => {
+                LinearGradient(start.interpolate(end, t))
+            }
+            (RadialGradient(start), RadialGradient(end)) => {
+                RadialGradient(start.interpolate(end, t))
+            }
+            (Color(_), _) => self.promote_to(other).interpolate(other, t),
+            (_, Color(_)) => self.interpolate(&other.promote_to(self), t),
+            _ => match t < 0.5 {
+                true => self.clone(),
+                false => other.clone(),
+            },
+        }
+    }
+
+    /// Converts a solid color into a copy of `gradient` with the color as only stop, gradients
+    /// and a solid `gradient` are returned unchanged.
+    fn promote_to(&self, gradient: &ColorLike) -> ColorLike {
+        let ColorLike::Color(color) = self else {
+            return self.clone();
+        };
+        let stops = vec![Stop::new(*color, 0.0)];
+
+        match gradient {
+            ColorLike::Color(_) => self.clone(),
+            ColorLike::LinearGradient(l_g) => {
+                let mut l_g = l_g.clone();
+                l_g.base_mut().set_stops(stops);
+                ColorLike::LinearGradient(l_g)
+            }
+            ColorLike::RadialGradient(r_g) => {
+                let mut r_g = r_g.clone();
+                r_g.base_mut().set_stops(stops);
+                ColorLike::RadialGradient(r_g)
+            }
+        }
+    }
+
+    /// Multiplies the alpha channel of the color or of every gradient stop with `opacity`.
+    pub(crate) fn multiply_opacity(&mut self, opacity: f64) {
+        match self {
+            ColorLike::Color(c) => {
+                let alpha = (c[3] as f64) * opacity.clamp(0.0, 1.0);
+                c[3] = alpha.round() as u8;
+            }
+            ColorLike::LinearGradient(l_g) => l_g.base_mut().multiply_opacity(opacity),
+            ColorLike::RadialGradient(r_g) => r_g.base_mut().multiply_opacity(opacity),
+        }
+    }
+
+    /// See [`BaseGradient::bake_transform`](crate::holder::gradient::base::BaseGradient::bake_transform).
+    pub(crate) fn bake_transform(&mut self, transform: DAffine2, bounding_box: (Point, Point)) {
+        match self {
+            ColorLike::Color(_) => (),
+            ColorLike::LinearGradient(l_g) => {
+                l_g.base_mut().bake_transform(transform, bounding_box)
+            }
+            ColorLike::RadialGradient(r_g) => {
+                r_g.base_mut().bake_transform(transform, bounding_box)
+            }
         }
     }
 }
@@ -41,7 +126,7 @@ impl TranslateIntoResvgGeneric<resvg::usvg::Opacity> for ColorLike {
     fn translate(&self) -> resvg::usvg::Opacity {
         match self {
             ColorLike::Color(c) => resvg::usvg::Opacity::new_u8(c[3]),
-            // TODO
+            // the alpha of a gradient is stored in its stops
             ColorLike::LinearGradient(_) | ColorLike::RadialGradient(_) => {
                 resvg::usvg::Opacity::ONE
             }
@@ -61,3 +146,78 @@ impl TranslateIntoResvgGeneric<resvg::usvg::Fill> for ColorLike {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::holder::gradient::base::BaseGradient;
+
+    const RED: Pixel = Pixel::new_raw([255, 0, 0, 255]);
+    const BLUE: Pixel = Pixel::new_raw([0, 0, 255, 255]);
+
+    fn linear(colors: Vec<Pixel>, point_2: Point) -> LinearGradient {
+        LinearGradient::new_with_points(Point::ZERO, point_2, BaseGradient::new_from_colors(colors))
+    }
+
+    #[test]
+    fn interpolates_stops_and_points() {
+        let start = ColorLike::LinearGradient(linear(vec![RED, BLUE], Point::new(1.0, 0.0)));
+        let mut end = linear(vec![BLUE, RED], Point::new(0.0, 1.0));
+        end.base_mut().stops_mut()[0].set_offset(0.5);
+        let end = ColorLike::LinearGradient(end);
+
+        match start.interpolate(&end, 0.5) {
+            ColorLike::LinearGradient(l_g) => {
+                assert_eq!(l_g.points(), (Point::ZERO, Point::new(0.5, 0.5)));
+
+                let stops = l_g.base().stops();
+                assert_eq!(stops[0], Stop::new(Pixel::new(128, 0, 128, 255), 0.25));
+                assert_eq!(stops[1], Stop::new(Pixel::new(128, 0, 128, 255), 1.0));
+            }
+            _ => panic!("expected a linear gradient"),
+        }
+
+        assert_eq!(start.interpolate(&end, 0.0), start);
+        assert_eq!(start.interpolate(&end, 1.0), end);
+    }
+
+    #[test]
+    fn samples_different_stop_counts() {
+        let start = linear(vec![RED, BLUE], Point::new(1.0, 0.0));
+        let end = linear(vec![RED, RED, RED], Point::new(1.0, 0.0));
+
+        let result = start.interpolate(&end, 1.0);
+        let offsets: Vec<f64> = result.base().stops().iter().map(Stop::offset).collect();
+        assert_eq!(offsets, vec![0.0, 0.5, 1.0]);
+        assert!(result.base().stops().iter().all(|stop| stop.color() == RED));
+
+        let result = start.interpolate(&end, 0.0);
+        assert_eq!(result.base().color_at(0.5), Pixel::new(128, 0, 128, 255));
+    }
+
+    #[test]
+    fn promotes_solid_colors() {
+        let gradient = ColorLike::LinearGradient(linear(vec![RED, BLUE], Point::new(1.0, 0.0)));
+        let color = ColorLike::Color(RED);
+
+        match color.interpolate(&gradient, 0.5) {
+            ColorLike::LinearGradient(l_g) => {
+                assert_eq!(l_g.base().color_at(0.0), RED);
+                assert_eq!(l_g.base().color_at(1.0), Pixel::new(128, 0, 128, 255));
+            }
+            _ => panic!("expected a linear gradient"),
+        }
+
+        match gradient.interpolate(&color, 1.0) {
+            ColorLike::LinearGradient(l_g) => {
+                assert!(l_g.base().stops().iter().all(|stop| stop.color() == RED))
+            }
+            _ => panic!("expected a linear gradient"),
+        }
+
+        assert_eq!(
+            ColorLike::Color(RED).interpolate(&ColorLike::Color(BLUE), 0.5),
+            ColorLike::Color(Pixel::new(128, 0, 128, 255))
+        );
+    }
+}
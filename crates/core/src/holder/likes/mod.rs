@@ -1,8 +1,13 @@
 pub mod color_like;
 pub mod path_like;
+pub mod path_measure;
+pub mod path_morph;
+pub mod path_parser;
 pub mod types_like;
 mod utils;
 
 pub use color_like::ColorLike;
 pub use path_like::PathLike;
+pub use path_measure::PathMeasure;
+pub use path_morph::PathMorph;
 pub use types_like::TypesLike;
@@ -1,7 +1,9 @@
 use approx::AbsDiffEq;
 use geo::{Centroid, LineString, Polygon};
-use resvg::usvg::{PathData, PathSegment};
+use glam::{DAffine2, DMat2};
+use resvg::usvg::{PathCommand, PathData, PathSegment};
 
+use crate::holder::likes::path_parser::{self, PathParseError};
 use crate::holder::likes::utils::{coord2_to_point, point_to_coord2};
 use crate::holder::utils::TranslateIntoResvgGeneric;
 use crate::point::Point;
@@ -10,10 +12,10 @@ use crate::point::Point;
 const DELTA: f64 = 0.0001;
 const BOUNDING_BOX_STEPS: u32 = 10;
 
-// TODO write custom `Deserialize`r and uncomment custom `Serialize`r
+/// A single svg path command.
+///
+/// The command is formatted and (de-)serialized in the svg path syntax, e.g. `C 0 5, 10 5, 10 0`.
 #[derive(Debug, Clone, Copy)]
-#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
-#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
 pub enum PathLike {
     /// Svg path: `M x y`
     Move(Point),
@@ -22,51 +24,174 @@ pub enum PathLike {
     /// Svg path: `C x y, x1 y1, x2 y2`
     /// end_point, control_point_start, control_point_end
     CurveTo(Point, Point, Point),
+    /// Svg path: `Q x1 y1, x y`
+    /// end_point, control_point
+    QuadTo(Point, Point),
+    /// Svg path: `T x y`
+    /// end_point, the control point is the reflection of the control point of the previous
+    /// [`PathLike::QuadTo`] or the current point
+    SmoothQuadTo(Point),
+    /// Svg path: `S x2 y2, x y`
+    /// end_point, control_point_end, the start control point is the reflection of the end control
+    /// point of the previous [`PathLike::CurveTo`] or the current point
+    SmoothCurveTo(Point, Point),
+    /// Svg path: `A rx ry x-axis-rotation large-arc-flag sweep-flag x y`
+    /// `x_axis_rotation` is in degrees
+    Arc {
+        end: Point,
+        radius: Point,
+        x_axis_rotation: f64,
+        large_arc: bool,
+        sweep: bool,
+    },
     /// Svg path: `Z`
     Close,
+
+    /// Svg path: `m dx dy`
+    MoveRelative(Point),
+    /// Svg path: `l dx dy`
+    LineRelative(Point),
+    /// Svg path: `c dx dy, dx1 dy1, dx2 dy2`
+    /// end_point, control_point_start, control_point_end
+    CurveToRelative(Point, Point, Point),
+    /// Svg path: `q dx1 dy1, dx dy`
+    /// end_point, control_point
+    QuadToRelative(Point, Point),
+    /// Svg path: `t dx dy`
+    SmoothQuadToRelative(Point),
+    /// Svg path: `s dx2 dy2, dx dy`
+    /// end_point, control_point_end
+    SmoothCurveToRelative(Point, Point),
+    /// Svg path: `a rx ry x-axis-rotation large-arc-flag sweep-flag dx dy`
+    ArcRelative {
+        end: Point,
+        radius: Point,
+        x_axis_rotation: f64,
+        large_arc: bool,
+        sweep: bool,
+    },
+}
+
+impl std::fmt::Display for PathLike {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // `f64` is formatted as short as possible without losing precision
+        let point = |p: &Point| format!("{} {}", p.x(), p.y());
+        let flag = |value: bool| match value {
+            true => "1",
+            false => "0",
+        };
+
+        match self {
+            PathLike::Move(p) => write!(f, "M {}", point(p)),
+            PathLike::MoveRelative(p) => write!(f, "m {}", point(p)),
+            PathLike::Line(p) => write!(f, "L {}", point(p)),
+            PathLike::LineRelative(p) => write!(f, "l {}", point(p)),
+            PathLike::CurveTo(e_p, c_s, c_e) => {
+                write!(f, "C {}, {}, {}", point(c_s), point(c_e), point(e_p))
+            }
+            PathLike::CurveToRelative(e_p, c_s, c_e) => {
+                write!(f, "c {}, {}, {}", point(c_s), point(c_e), point(e_p))
+            }
+            PathLike::QuadTo(e_p, c) => write!(f, "Q {}, {}", point(c), point(e_p)),
+            PathLike::QuadToRelative(e_p, c) => write!(f, "q {}, {}", point(c), point(e_p)),
+            PathLike::SmoothQuadTo(e_p) => write!(f, "T {}", point(e_p)),
+            PathLike::SmoothQuadToRelative(e_p) => write!(f, "t {}", point(e_p)),
+            PathLike::SmoothCurveTo(e_p, c_e) => write!(f, "S {}, {}", point(c_e), point(e_p)),
+            PathLike::SmoothCurveToRelative(e_p, c_e) => {
+                write!(f, "s {}, {}", point(c_e), point(e_p))
+            }
+            PathLike::Arc {
+                end,
+                radius,
+                x_axis_rotation,
+                large_arc,
+                sweep,
+            } => write!(
+                f,
+                "A {} {} {} {} {}",
+                point(radius),
+                x_axis_rotation,
+                flag(*large_arc),
+                flag(*sweep),
+                point(end)
+            ),
+            PathLike::ArcRelative {
+                end,
+                radius,
+                x_axis_rotation,
+                large_arc,
+                sweep,
+            } => write!(
+                f,
+                "a {} {} {} {} {}",
+                point(radius),
+                x_axis_rotation,
+                flag(*large_arc),
+                flag(*sweep),
+                point(end)
+            ),
+            PathLike::Close => write!(f, "Z"),
+        }
+    }
+}
+
+impl std::str::FromStr for PathLike {
+    type Err = PathParseError;
+
+    /// Parses a single command, see [`PathLike::parse`] to parse a whole path.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        path_parser::parse_single(s)
+    }
 }
 
-// TODO custom serde
-/*
-impl Serialize for PathLike {
+#[cfg(feature = "serialize")]
+impl serde::Serialize for PathLike {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        #[inline]
-        fn format_float(value: f64) -> String {
-            if value.trunc() == value {
-                format!("{}", value as u32)
-            } else {
-                format!("{:.4}", value)
-            }
-        }
+        serializer.collect_str(self)
+    }
+}
 
-        match self {
-            PathLike::Move(p) => serializer.serialize_str(&format!(
-                "M {} {}",
-                format_float(p.x()),
-                format_float(p.y())
-            )),
-            PathLike::Line(p) => serializer.serialize_str(&format!(
-                "L {} {}",
-                format_float(p.x()),
-                format_float(p.y())
-            )),
-            PathLike::CurveTo(e_p, c_s, c_e) => serializer.serialize_str(&format!(
-                "C {} {}, {} {}, {} {}",
-                format_float(c_s.x()),
-                format_float(c_s.y()),
-                format_float(c_e.x()),
-                format_float(c_e.y()),
-                format_float(e_p.x()),
-                format_float(e_p.y())
-            )),
-            PathLike::Close => serializer.serialize_str("Z"),
-        }
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for PathLike {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// (De-)serializes a whole path as one svg path string, use it with
+/// `#[serde(with = "rusvid_core::holder::likes::path_like::path_string")]`.
+#[cfg(any(feature = "serialize", feature = "deserialize"))]
+pub mod path_string {
+    use super::PathLike;
+
+    #[cfg(feature = "serialize")]
+    pub fn serialize<S>(path: &[PathLike], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&PathLike::to_path_string(path))
+    }
+
+    #[cfg(feature = "deserialize")]
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<PathLike>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::Deserialize;
+
+        let value = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+
+        PathLike::parse(&value).map_err(serde::de::Error::custom)
     }
 }
-*/
 
 impl PartialEq<PathLike> for PathLike {
     fn eq(&self, other: &PathLike) -> bool {
@@ -78,18 +203,86 @@ impl PartialEq<PathLike> for PathLike {
 
         match (self, other) {
             (Close, Close) => true,
-            (Move(p1), Move(p2)) => compare_points(p1, p2),
-            (Line(p1), Line(p2)) => compare_points(p1, p2),
-            (CurveTo(pe1, pc11, pc12), CurveTo(pe2, pc21, pc22)) => {
+            (Move(p1), Move(p2))
+            | (Line(p1), Line(p2))
+            | (SmoothQuadTo(p1), SmoothQuadTo(p2))
+            | (MoveRelative(p1), MoveRelative(p2))
+            | (LineRelative(p1), LineRelative(p2))
+            | (SmoothQuadToRelative(p1), SmoothQuadToRelative(p2)) => compare_points(p1, p2),
+            (QuadTo(pe1, pc1), QuadTo(pe2, pc2))
+            | (SmoothCurveTo(pe1, pc1), SmoothCurveTo(pe2, pc2))
+            | (QuadToRelative(pe1, pc1), QuadToRelative(pe2, pc2))
+            | (SmoothCurveToRelative(pe1, pc1), SmoothCurveToRelative(pe2, pc2)) => {
+                compare_points(pe1, pe2) && compare_points(pc1, pc2)
+            }
+            (CurveTo(pe1, pc11, pc12), CurveTo(pe2, pc21, pc22))
+            | (CurveToRelative(pe1, pc11, pc12), CurveToRelative(pe2, pc21, pc22)) => {
                 compare_points(pe1, pe2) && compare_points(pc11, pc21) && compare_points(pc12, pc22)
             }
+            (
+                Arc {
+                    end: e1,
+                    radius: r1,
+                    x_axis_rotation: x1,
+                    large_arc: l1,
+                    sweep: s1,
+                },
+                Arc {
+                    end: e2,
+                    radius: r2,
+                    x_axis_rotation: x2,
+                    large_arc: l2,
+                    sweep: s2,
+                },
+            )
+            | (
+                ArcRelative {
+                    end: e1,
+                    radius: r1,
+                    x_axis_rotation: x1,
+                    large_arc: l1,
+                    sweep: s1,
+                },
+                ArcRelative {
+                    end: e2,
+                    radius: r2,
+                    x_axis_rotation: x2,
+                    large_arc: l2,
+                    sweep: s2,
+                },
+            ) => {
+                compare_points(e1, e2)
+                    && compare_points(r1, r2)
+                    && x1.abs_diff_eq(x2, DELTA)
+                    && l1 == l2
+                    && s1 == s2
+            }
             _ => false,
         }
     }
 }
 
 impl PathLike {
+    /// Parses svg path data like `M 10 10 C 20 0, 30 0, 40 10 Z`.
+    ///
+    /// All commands of the svg path syntax are supported, horizontal and vertical lines are
+    /// parsed as [`PathLike::Line`] or [`PathLike::LineRelative`]. The error contains the
+    /// position of the invalid data.
+    pub fn parse(data: &str) -> Result<Vec<PathLike>, PathParseError> {
+        path_parser::parse(data)
+    }
+
+    /// Formats the path in the svg path syntax, the inverse of [`PathLike::parse`].
+    pub fn to_path_string(path: &[PathLike]) -> String {
+        path.iter()
+            .map(|path_like| path_like.to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
     pub(crate) fn to_geo_polygon(path: &[PathLike]) -> Polygon {
+        let path = Self::simplify(path);
+
         let mut last_move = Point::new_symmetric(0.0);
         let mut last_point = Point::new_symmetric(0.0);
         let exterior = path
@@ -131,6 +324,7 @@ impl PathLike {
                     last_point = last_move.clone();
                     vec![(last_move.x(), last_move.y())]
                 }
+                _ => unreachable!("the path is simplified"),
             })
             .flatten()
             .collect::<Vec<(f64, f64)>>();
@@ -155,10 +349,15 @@ impl PathLike {
         center
     }
 
+    /// Smallest and biggest corner of the area covered by the path, both are [`Point::ZERO`] for
+    /// an empty path.
     pub fn bounding_box(path: &[PathLike]) -> (Point, Point) {
-        let mut smaller_corner = match path[0] {
-            PathLike::Move(p) => p,
-            _ => todo!(),
+        let path = Self::simplify(path);
+
+        let mut smaller_corner = match path.first() {
+            Some(PathLike::Move(p)) => *p,
+            Some(_) => unreachable!("a simplified path always starts with a move"),
+            None => return (Point::ZERO, Point::ZERO),
         };
         let mut bigger_corner = smaller_corner;
 
@@ -218,32 +417,265 @@ impl PathLike {
     }
 
     pub fn type_equal(&self, other: &PathLike) -> bool {
-        debug_assert_eq!(std::mem::variant_count::<PathLike>(), 4);
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
 
+    /// Returns `true` if the points of the command are relative to the current point.
+    pub fn is_relative(&self) -> bool {
         matches!(
-            (self, other),
-            (PathLike::Move(_), PathLike::Move(_))
-                | (PathLike::Line(_), PathLike::Line(_))
-                | (PathLike::Close, PathLike::Close)
-                | (PathLike::CurveTo(_, _, _), PathLike::CurveTo(_, _, _))
+            self,
+            PathLike::MoveRelative(_)
+                | PathLike::LineRelative(_)
+                | PathLike::CurveToRelative(_, _, _)
+                | PathLike::QuadToRelative(_, _)
+                | PathLike::SmoothQuadToRelative(_)
+                | PathLike::SmoothCurveToRelative(_, _)
+                | PathLike::ArcRelative { .. }
         )
     }
 
-    pub fn extend_path_from_self(&self, path: &mut PathData) {
-        match self {
+    /// Returns the end point of the command as it's stored, for relative commands the end point
+    /// is relative to the current point.
+    pub(crate) fn end_point(&self) -> Option<Point> {
+        match *self {
+            PathLike::Move(p)
+            | PathLike::Line(p)
+            | PathLike::CurveTo(p, _, _)
+            | PathLike::QuadTo(p, _)
+            | PathLike::SmoothQuadTo(p)
+            | PathLike::SmoothCurveTo(p, _)
+            | PathLike::Arc { end: p, .. }
+            | PathLike::MoveRelative(p)
+            | PathLike::LineRelative(p)
+            | PathLike::CurveToRelative(p, _, _)
+            | PathLike::QuadToRelative(p, _)
+            | PathLike::SmoothQuadToRelative(p)
+            | PathLike::SmoothCurveToRelative(p, _)
+            | PathLike::ArcRelative { end: p, .. } => Some(p),
+            PathLike::Close => None,
+        }
+    }
+
+    /// Converts all relative commands into absolute commands.
+    pub fn to_absolute(path: &[PathLike]) -> Vec<PathLike> {
+        let mut current_point = Point::ZERO;
+        let mut sub_path_start = Point::ZERO;
+
+        path.iter()
+            .map(|path_like| {
+                let c = current_point;
+
+                let absolute = match *path_like {
+                    PathLike::MoveRelative(p) => PathLike::Move(c + p),
+                    PathLike::LineRelative(p) => PathLike::Line(c + p),
+                    PathLike::CurveToRelative(end, c_s, c_e) => {
+                        PathLike::CurveTo(c + end, c + c_s, c + c_e)
+                    }
+                    PathLike::QuadToRelative(end, control) => {
+                        PathLike::QuadTo(c + end, c + control)
+                    }
+                    PathLike::SmoothQuadToRelative(end) => PathLike::SmoothQuadTo(c + end),
+                    PathLike::SmoothCurveToRelative(end, c_e) => {
+                        PathLike::SmoothCurveTo(c + end, c + c_e)
+                    }
+                    PathLike::ArcRelative {
+                        end,
+                        radius,
+                        x_axis_rotation,
+                        large_arc,
+                        sweep,
+                    } => PathLike::Arc {
+                        end: c + end,
+                        radius,
+                        x_axis_rotation,
+                        large_arc,
+                        sweep,
+                    },
+                    absolute => absolute,
+                };
+
+                current_point = match absolute {
+                    PathLike::Move(p) => {
+                        sub_path_start = p;
+                        p
+                    }
+                    PathLike::Close => sub_path_start,
+                    _ => absolute.end_point().unwrap_or(current_point),
+                };
+
+                absolute
+            })
+            .collect()
+    }
+
+    /// Converts the path into only [`PathLike::Move`], [`PathLike::Line`], [`PathLike::CurveTo`]
+    /// and [`PathLike::Close`] commands with absolute points. Quadratic curves and arcs are
+    /// converted into cubic curves.
+    pub fn simplify(path: &[PathLike]) -> Vec<PathLike> {
+        let mut data = PathData::new();
+        Self::extend_path_from_slice(&mut data, path);
+
+        data.segments().map(PathLike::from_path_segment).collect()
+    }
+
+    /// Applies the affine `transform` to all points of the path, the path is converted into
+    /// absolute commands first. Unlike transforming every command on its own a leading
+    /// [`PathLike::MoveRelative`] is therefore moved like an absolute point.
+    pub fn transform_path(path: &[PathLike], transform: &DAffine2) -> Vec<PathLike> {
+        Self::to_absolute(path)
+            .iter()
+            .map(|path_like| path_like.transform(transform))
+            .collect()
+    }
+
+    /// Applies the affine `transform` to all points of the command, for relative commands only
+    /// the linear part of the transformation is used. To transform a whole path use
+    /// [`PathLike::transform_path`].
+    ///
+    /// The radii and the rotation of arcs are adjusted so that the arc stays on the transformed
+    /// ellipse.
+    pub fn transform(&self, transform: &DAffine2) -> PathLike {
+        let point = |p: Point| Point::from(transform.transform_point2(p.into()));
+        let vector = |p: Point| Point::from(transform.transform_vector2(p.into()));
+
+        match *self {
+            PathLike::Move(p) => PathLike::Move(point(p)),
+            PathLike::Line(p) => PathLike::Line(point(p)),
+            PathLike::CurveTo(end, c_s, c_e) => {
+                PathLike::CurveTo(point(end), point(c_s), point(c_e))
+            }
+            PathLike::QuadTo(end, control) => PathLike::QuadTo(point(end), point(control)),
+            PathLike::SmoothQuadTo(end) => PathLike::SmoothQuadTo(point(end)),
+            PathLike::SmoothCurveTo(end, c_e) => PathLike::SmoothCurveTo(point(end), point(c_e)),
+            PathLike::Arc {
+                end,
+                radius,
+                x_axis_rotation,
+                large_arc,
+                sweep,
+            } => {
+                let (radius, x_axis_rotation, sweep) =
+                    transform_arc(transform, radius, x_axis_rotation, sweep);
+
+                PathLike::Arc {
+                    end: point(end),
+                    radius,
+                    x_axis_rotation,
+                    large_arc,
+                    sweep,
+                }
+            }
+            PathLike::Close => PathLike::Close,
+            PathLike::MoveRelative(p) => PathLike::MoveRelative(vector(p)),
+            PathLike::LineRelative(p) => PathLike::LineRelative(vector(p)),
+            PathLike::CurveToRelative(end, c_s, c_e) => {
+                PathLike::CurveToRelative(vector(end), vector(c_s), vector(c_e))
+            }
+            PathLike::QuadToRelative(end, control) => {
+                PathLike::QuadToRelative(vector(end), vector(control))
+            }
+            PathLike::SmoothQuadToRelative(end) => PathLike::SmoothQuadToRelative(vector(end)),
+            PathLike::SmoothCurveToRelative(end, c_e) => {
+                PathLike::SmoothCurveToRelative(vector(end), vector(c_e))
+            }
+            PathLike::ArcRelative {
+                end,
+                radius,
+                x_axis_rotation,
+                large_arc,
+                sweep,
+            } => {
+                let (radius, x_axis_rotation, sweep) =
+                    transform_arc(transform, radius, x_axis_rotation, sweep);
+
+                PathLike::ArcRelative {
+                    end: vector(end),
+                    radius,
+                    x_axis_rotation,
+                    large_arc,
+                    sweep,
+                }
+            }
+        }
+    }
+
+    /// Adds an absolute command to `path`, smooth curves must already be resolved into
+    /// [`PathLike::QuadTo`] or [`PathLike::CurveTo`].
+    fn extend_path_from_absolute(&self, path: &mut PathData) {
+        match *self {
             PathLike::Move(point) => path.push_move_to(point.x(), point.y()),
             PathLike::Line(point) => path.push_line_to(point.x(), point.y()),
             PathLike::CurveTo(pe, pc1, pc2) => {
                 path.push_curve_to(pc1.x(), pc1.y(), pc2.x(), pc2.y(), pe.x(), pe.y())
             }
+            PathLike::QuadTo(pe, pc) => path.push_quad_to(pc.x(), pc.y(), pe.x(), pe.y()),
+            PathLike::Arc {
+                end,
+                radius,
+                x_axis_rotation,
+                large_arc,
+                sweep,
+            } => path.push_arc_to(
+                radius.x(),
+                radius.y(),
+                x_axis_rotation,
+                large_arc,
+                sweep,
+                end.x(),
+                end.y(),
+            ),
             PathLike::Close => path.push_close_path(),
+            _ => unreachable!("only absolute and resolved commands can be added"),
         }
     }
 
+    /// Adds all commands to `path`, relative commands are resolved with the current point and
+    /// smooth curves with the control point of the previous curve.
     pub fn extend_path_from_slice(path: &mut PathData, slice: &[Self]) {
-        slice
-            .iter()
-            .for_each(|item| item.extend_path_from_self(path))
+        let mut current_point = Point::ZERO;
+        let mut sub_path_start = Point::ZERO;
+        let mut previous = PathLike::Close;
+
+        for path_like in Self::to_absolute(slice) {
+            let resolved = match path_like {
+                PathLike::SmoothQuadTo(end) => {
+                    let control = match previous {
+                        PathLike::QuadTo(_, control) => current_point * 2.0 - control,
+                        _ => current_point,
+                    };
+
+                    PathLike::QuadTo(end, control)
+                }
+                PathLike::SmoothCurveTo(end, c_e) => {
+                    let c_s = match previous {
+                        PathLike::CurveTo(_, _, c_e) => current_point * 2.0 - c_e,
+                        _ => current_point,
+                    };
+
+                    PathLike::CurveTo(end, c_s, c_e)
+                }
+                absolute => absolute,
+            };
+
+            // every segment in resvg needs a start point, after a close the next segment starts
+            // at the start of the closed sub path
+            let needs_start = matches!(path.commands().last(), None | Some(PathCommand::ClosePath));
+            if needs_start && !matches!(resolved, PathLike::Move(_)) {
+                path.push_move_to(current_point.x(), current_point.y());
+            }
+
+            resolved.extend_path_from_absolute(path);
+
+            current_point = match resolved {
+                PathLike::Move(p) => {
+                    sub_path_start = p;
+                    p
+                }
+                PathLike::Close => sub_path_start,
+                _ => resolved.end_point().unwrap_or(current_point),
+            };
+            previous = resolved;
+        }
     }
 
     pub fn from_path_segment(other: PathSegment) -> PathLike {
@@ -263,27 +695,46 @@ impl PathLike {
     }
 }
 
-impl TranslateIntoResvgGeneric<resvg::usvg::PathSegment> for PathLike {
-    fn translate(&self) -> resvg::usvg::PathSegment {
-        match self {
-            PathLike::Move(point) => PathSegment::MoveTo {
-                x: point.x(),
-                y: point.y(),
-            },
-            PathLike::Line(point) => PathSegment::LineTo {
-                x: point.x(),
-                y: point.y(),
-            },
-            PathLike::CurveTo(pe, pc1, pc2) => PathSegment::CurveTo {
-                x1: pc1.x(),
-                y1: pc1.y(),
-                x2: pc2.x(),
-                y2: pc2.y(),
-                x: pe.x(),
-                y: pe.y(),
-            },
-            PathLike::Close => PathSegment::ClosePath,
-        }
+/// Transforms the ellipse of an arc, returns the new radii, rotation in degrees and sweep flag.
+fn transform_arc(
+    transform: &DAffine2,
+    radius: Point,
+    x_axis_rotation: f64,
+    sweep: bool,
+) -> (Point, f64, bool) {
+    // a translation doesn't change the ellipse
+    if transform.matrix2 == DMat2::IDENTITY {
+        return (radius, x_axis_rotation, sweep);
+    }
+
+    // the ellipse is the unit circle transformed by `ellipse`, the radii are the singular values
+    // and the rotation is the direction of the first left singular vector
+    let ellipse = transform.matrix2
+        * DMat2::from_angle(x_axis_rotation.to_radians())
+        * DMat2::from_diagonal(radius.into());
+    let m = ellipse * ellipse.transpose();
+    let (p, q, r) = (m.x_axis.x, m.y_axis.x, m.y_axis.y);
+
+    let mean = (p + r) / 2.0;
+    let diff = ((p - r) / 2.0).hypot(q);
+    let radius = Point::new((mean + diff).max(0.0).sqrt(), (mean - diff).max(0.0).sqrt());
+    let x_axis_rotation = (0.5 * (2.0 * q).atan2(p - r)).to_degrees();
+
+    // a mirroring transformation changes the direction of the arc
+    let sweep = match transform.matrix2.determinant() < 0.0 {
+        true => !sweep,
+        false => sweep,
+    };
+
+    (radius, x_axis_rotation, sweep)
+}
+
+impl TranslateIntoResvgGeneric<PathData> for [PathLike] {
+    fn translate(&self) -> PathData {
+        let mut path = PathData::new();
+        PathLike::extend_path_from_slice(&mut path, self);
+
+        path
     }
 }
 
@@ -336,13 +787,17 @@ mod tests {
         }
     }
 
-    mod to_usvg_path_segment {
+    mod to_usvg_path_data {
         use super::*;
 
+        fn last_segment(path: &[PathLike]) -> PathSegment {
+            path.translate().segments().last().unwrap()
+        }
+
         #[test]
         fn direct_move() {
             assert!(equal_path_segment(
-                PathLike::Move(Point::ZERO).translate(),
+                last_segment(&[PathLike::Move(Point::ZERO)]),
                 PathSegment::MoveTo { x: 0.0, y: 0.0 }
             ));
         }
@@ -350,7 +805,7 @@ mod tests {
         #[test]
         fn direct_line() {
             assert!(equal_path_segment(
-                PathLike::Line(Point::ZERO).translate(),
+                last_segment(&[PathLike::Line(Point::ZERO)]),
                 PathSegment::LineTo { x: 0.0, y: 0.0 }
             ));
         }
@@ -358,7 +813,7 @@ mod tests {
         #[test]
         fn direct_close() {
             assert!(equal_path_segment(
-                PathLike::Close.translate(),
+                last_segment(&[PathLike::Move(Point::ZERO), PathLike::Close]),
                 PathSegment::ClosePath
             ));
         }
@@ -366,7 +821,7 @@ mod tests {
         #[test]
         fn direct_curve_to() {
             assert!(equal_path_segment(
-                PathLike::CurveTo(Point::ZERO, Point::ONE, Point::NEG_ONE).translate(),
+                last_segment(&[PathLike::CurveTo(Point::ZERO, Point::ONE, Point::NEG_ONE)]),
                 PathSegment::CurveTo {
                     x1: 1.0,
                     y1: 1.0,
@@ -377,6 +832,228 @@ mod tests {
                 }
             ))
         }
+
+        #[test]
+        fn quad_to() {
+            let path = [
+                PathLike::Move(Point::ZERO),
+                PathLike::QuadTo(Point::new(30.0, 0.0), Point::new(15.0, 30.0)),
+            ];
+
+            assert!(equal_path_segment(
+                last_segment(&path),
+                PathSegment::CurveTo {
+                    x1: 10.0,
+                    y1: 20.0,
+                    x2: 20.0,
+                    y2: 20.0,
+                    x: 30.0,
+                    y: 0.0
+                }
+            ));
+        }
+
+        #[test]
+        fn smooth_curves_reflect_the_control_point() {
+            let path = [
+                PathLike::Move(Point::ZERO),
+                PathLike::CurveTo(
+                    Point::new(10.0, 0.0),
+                    Point::new(0.0, 10.0),
+                    Point::new(10.0, 10.0),
+                ),
+                PathLike::SmoothCurveTo(Point::new(20.0, 0.0), Point::new(20.0, -10.0)),
+            ];
+
+            assert_eq!(
+                PathLike::simplify(&path)[2],
+                PathLike::CurveTo(
+                    Point::new(20.0, 0.0),
+                    Point::new(10.0, -10.0),
+                    Point::new(20.0, -10.0)
+                )
+            );
+
+            let path = [
+                PathLike::Move(Point::ZERO),
+                PathLike::QuadTo(Point::new(10.0, 0.0), Point::new(5.0, 15.0)),
+                PathLike::SmoothQuadTo(Point::new(20.0, 0.0)),
+            ];
+            let reflected = [
+                PathLike::Move(Point::ZERO),
+                PathLike::QuadTo(Point::new(10.0, 0.0), Point::new(5.0, 15.0)),
+                PathLike::QuadTo(Point::new(20.0, 0.0), Point::new(15.0, -15.0)),
+            ];
+
+            assert_eq!(PathLike::simplify(&path), PathLike::simplify(&reflected));
+        }
+
+        #[test]
+        fn starts_after_close_at_the_sub_path_start() {
+            let path = [
+                PathLike::Move(Point::new(5.0, 5.0)),
+                PathLike::Line(Point::new(10.0, 5.0)),
+                PathLike::Close,
+                PathLike::QuadTo(Point::new(10.0, 10.0), Point::new(5.0, 10.0)),
+            ];
+
+            assert_eq!(
+                PathLike::simplify(&path)[3],
+                PathLike::Move(Point::new(5.0, 5.0))
+            );
+        }
+    }
+
+    #[test]
+    fn to_absolute() {
+        let path = [
+            PathLike::MoveRelative(Point::new(10.0, 10.0)),
+            PathLike::LineRelative(Point::new(10.0, 0.0)),
+            PathLike::QuadToRelative(Point::new(0.0, 10.0), Point::new(5.0, 5.0)),
+            PathLike::Close,
+            PathLike::ArcRelative {
+                end: Point::new(0.0, 10.0),
+                radius: Point::new(5.0, 5.0),
+                x_axis_rotation: 0.0,
+                large_arc: false,
+                sweep: true,
+            },
+        ];
+
+        assert_eq!(
+            PathLike::to_absolute(&path),
+            vec![
+                PathLike::Move(Point::new(10.0, 10.0)),
+                PathLike::Line(Point::new(20.0, 10.0)),
+                PathLike::QuadTo(Point::new(20.0, 20.0), Point::new(25.0, 15.0)),
+                PathLike::Close,
+                PathLike::Arc {
+                    end: Point::new(10.0, 20.0),
+                    radius: Point::new(5.0, 5.0),
+                    x_axis_rotation: 0.0,
+                    large_arc: false,
+                    sweep: true,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn bounding_box_of_arc() {
+        // half circle from the left to the right side, bulging upwards
+        let path = [
+            PathLike::Move(Point::new(0.0, 10.0)),
+            PathLike::Arc {
+                end: Point::new(20.0, 10.0),
+                radius: Point::new(10.0, 10.0),
+                x_axis_rotation: 0.0,
+                large_arc: false,
+                sweep: true,
+            },
+        ];
+
+        let (min, max) = PathLike::bounding_box(&path);
+        assert!(min.abs_diff_eq(&Point::new(0.0, 0.0), 0.1), "{min:?}");
+        assert!(max.abs_diff_eq(&Point::new(20.0, 10.0), 0.1), "{max:?}");
+    }
+
+    #[test]
+    fn bounding_box_of_empty_path() {
+        assert_eq!(PathLike::bounding_box(&[]), (Point::ZERO, Point::ZERO));
+    }
+
+    mod transform {
+        use super::*;
+
+        fn arc(radius: Point, x_axis_rotation: f64, sweep: bool) -> PathLike {
+            PathLike::Arc {
+                end: Point::new(10.0, 0.0),
+                radius,
+                x_axis_rotation,
+                large_arc: false,
+                sweep,
+            }
+        }
+
+        #[test]
+        fn relative_commands_ignore_the_translation() {
+            let transform = DAffine2::from_translation((5.0, 5.0).into());
+
+            assert_eq!(
+                PathLike::Line(Point::ONE).transform(&transform),
+                PathLike::Line(Point::new(6.0, 6.0))
+            );
+            assert_eq!(
+                PathLike::LineRelative(Point::ONE).transform(&transform),
+                PathLike::LineRelative(Point::ONE)
+            );
+        }
+
+        #[test]
+        fn moves_relative_path() {
+            let path = PathLike::parse("m 10 10 l 10 0 l 0 10 z").unwrap();
+            let transform = DAffine2::from_translation((100.0, 50.0).into());
+
+            let moved = PathLike::transform_path(&path, &transform);
+            assert_eq!(
+                moved,
+                vec![
+                    PathLike::Move(Point::new(110.0, 60.0)),
+                    PathLike::Line(Point::new(120.0, 60.0)),
+                    PathLike::Line(Point::new(120.0, 70.0)),
+                    PathLike::Close,
+                ]
+            );
+
+            let scaled = PathLike::transform_path(&path, &DAffine2::from_scale((2.0, 2.0).into()));
+            assert_eq!(scaled[0], PathLike::Move(Point::new(20.0, 20.0)));
+            assert_eq!(scaled[2], PathLike::Line(Point::new(40.0, 40.0)));
+        }
+
+        #[test]
+        fn scales_arcs() {
+            let transform = DAffine2::from_scale((2.0, 3.0).into());
+
+            assert_eq!(
+                arc(Point::new(5.0, 4.0), 0.0, true).transform(&transform),
+                PathLike::Arc {
+                    end: Point::new(20.0, 0.0),
+                    radius: Point::new(12.0, 10.0),
+                    x_axis_rotation: 90.0,
+                    large_arc: false,
+                    sweep: true,
+                }
+            );
+        }
+
+        #[test]
+        fn rotates_arcs() {
+            let transform = DAffine2::from_angle(30.0_f64.to_radians());
+
+            match arc(Point::new(5.0, 2.0), 10.0, true).transform(&transform) {
+                PathLike::Arc {
+                    radius,
+                    x_axis_rotation,
+                    sweep,
+                    ..
+                } => {
+                    assert!(radius.abs_diff_eq(&Point::new(5.0, 2.0), DELTA));
+                    assert!((x_axis_rotation - 40.0).abs() < DELTA);
+                    assert!(sweep);
+                }
+                other => panic!("expected an arc, got {other:?}"),
+            }
+        }
+
+        #[test]
+        fn mirroring_flips_the_sweep() {
+            let transform = DAffine2::from_scale((-1.0, 1.0).into());
+
+            match arc(Point::new(5.0, 5.0), 0.0, true).transform(&transform) {
+                PathLike::Arc { sweep, .. } => assert!(!sweep),
+                other => panic!("expected an arc, got {other:?}"),
+            }
+        }
     }
 
     mod equal {
@@ -419,6 +1096,59 @@ mod tests {
         }
     }
 
+    mod path_string {
+        use super::*;
+
+        const PATH: &str =
+            "M 10 10 l 5.5 -5 C 0 5, 10 5, 10 0 q 1 1, 2 2 T 0 0 s 1 2, 3 4 A 5 5 45 1 0 -1.25 2 Z";
+
+        #[test]
+        fn round_trip() {
+            let path = PathLike::parse(PATH).unwrap();
+
+            assert_eq!(PathLike::to_path_string(&path), PATH);
+        }
+
+        #[test]
+        fn single_command() {
+            let curve = PathLike::CurveTo(
+                Point::new(10.0, 0.0),
+                Point::new(0.0, 5.0),
+                Point::new(10.0, 5.0),
+            );
+
+            assert_eq!(curve.to_string(), "C 0 5, 10 5, 10 0");
+            assert_eq!("C 0 5, 10 5, 10 0".parse::<PathLike>(), Ok(curve));
+        }
+
+        #[cfg(feature = "deserialize")]
+        #[test]
+        fn deserialize() {
+            use serde::de::value::{Error, StrDeserializer};
+            use serde::de::IntoDeserializer;
+            use serde::Deserialize;
+
+            let deserializer: StrDeserializer<Error> = "L 1 2".into_deserializer();
+            assert_eq!(
+                PathLike::deserialize(deserializer).unwrap(),
+                PathLike::Line(Point::new(1.0, 2.0))
+            );
+
+            let deserializer: StrDeserializer<Error> = "M 0 0 L 1 2 Z".into_deserializer();
+            assert_eq!(
+                super::super::path_string::deserialize(deserializer).unwrap(),
+                vec![
+                    PathLike::Move(Point::ZERO),
+                    PathLike::Line(Point::new(1.0, 2.0)),
+                    PathLike::Close
+                ]
+            );
+
+            let deserializer: StrDeserializer<Error> = "L 1".into_deserializer();
+            assert!(PathLike::deserialize(deserializer).is_err());
+        }
+    }
+
     #[test]
     fn type_equal() {
         assert!(PathLike::Move(Point::ONE).type_equal(&PathLike::Move(Point::ZERO)));
@@ -430,5 +1160,6 @@ mod tests {
         assert!(!PathLike::Move(Point::ONE).type_equal(&PathLike::Line(Point::ZERO)));
         assert!(!PathLike::Line(Point::ONE).type_equal(&PathLike::Close));
         assert!(!PathLike::Close.type_equal(&PathLike::Move(Point::ZERO)));
+        assert!(!PathLike::Move(Point::ONE).type_equal(&PathLike::MoveRelative(Point::ONE)));
     }
 }
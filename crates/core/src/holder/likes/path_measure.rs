@@ -0,0 +1,458 @@
+use flo_curves::bezier::{curve_length, BezierCurve, BezierCurveFactory, Curve, Tangent};
+use flo_curves::Coord2;
+
+use crate::holder::likes::path_like::PathLike;
+use crate::holder::likes::utils::{coord2_to_point, point_to_coord2};
+use crate::point::Point;
+
+/// Maximum error when estimating the length of a curve.
+const MAX_LENGTH_ERROR: f64 = 0.0001;
+
+/// Binary search steps to find the position of a length on a curve.
+const SEARCH_STEPS: usize = 32;
+
+#[derive(Debug, Clone, Copy)]
+enum SegmentKind {
+    Line(Point, Point),
+    Curve(Curve<Coord2>),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Segment {
+    kind: SegmentKind,
+
+    /// Length of the path before this segment
+    offset: f64,
+    length: f64,
+
+    /// Index of the sub-path, a sub-path starts with a [`PathLike::Move`]
+    sub_path: usize,
+    /// The segment is the line of a [`PathLike::Close`]
+    closes: bool,
+}
+
+impl Segment {
+    /// Position `t` between `0.0` and `1.0` on the segment where the length from the start of the
+    /// segment is `length`.
+    fn position_at_length(&self, length: f64) -> f64 {
+        if self.length <= 0.0 {
+            return 0.0;
+        }
+
+        match self.kind {
+            SegmentKind::Line(_, _) => (length / self.length).clamp(0.0, 1.0),
+            SegmentKind::Curve(curve) => {
+                let (mut lower, mut upper) = (0.0, 1.0);
+
+                for _ in 0..SEARCH_STEPS {
+                    let middle = (lower + upper) / 2.0;
+
+                    match curve_length(&curve.section(0.0, middle), MAX_LENGTH_ERROR) < length {
+                        true => lower = middle,
+                        false => upper = middle,
+                    }
+                }
+
+                (lower + upper) / 2.0
+            }
+        }
+    }
+
+    fn point_at(&self, t: f64) -> Point {
+        match self.kind {
+            SegmentKind::Line(start, end) => start + (end - start) * t,
+            SegmentKind::Curve(curve) => coord2_to_point(&curve.point_at_pos(t)),
+        }
+    }
+
+    /// Direction of the segment at `t`, the vector isn't normalised.
+    fn tangent_at(&self, t: f64) -> Point {
+        match self.kind {
+            SegmentKind::Line(start, end) => end - start,
+            SegmentKind::Curve(curve) => {
+                let tangent = coord2_to_point(&Tangent::from(&curve).tangent(t));
+
+                // control points on the start or end point have no direction at this point
+                match tangent == Point::ZERO {
+                    true => {
+                        coord2_to_point(&curve.end_point()) - coord2_to_point(&curve.start_point())
+                    }
+                    false => tangent,
+                }
+            }
+        }
+    }
+
+    /// The part of the segment between `t_start` and `t_end` as command, the start point of the
+    /// part is the current point.
+    fn section(&self, t_start: f64, t_end: f64) -> PathLike {
+        match self.kind {
+            SegmentKind::Line(_, _) => PathLike::Line(self.point_at(t_end)),
+            SegmentKind::Curve(curve) => {
+                let section = curve.section(t_start, t_end);
+                let (c_s, c_e) = section.control_points();
+
+                PathLike::CurveTo(
+                    coord2_to_point(&section.end_point()),
+                    coord2_to_point(&c_s),
+                    coord2_to_point(&c_e),
+                )
+            }
+        }
+    }
+}
+
+/// Arc-length parameterisation of a path, the length of curves is estimated.
+///
+/// A [`PathLike::Close`] counts as line back to the start of the sub-path, a
+/// [`PathLike::Move`] has no length.
+#[derive(Debug, Clone)]
+pub struct PathMeasure {
+    segments: Vec<Segment>,
+    length: f64,
+}
+
+impl PathMeasure {
+    pub fn new(path: &[PathLike]) -> Self {
+        let mut segments = Vec::new();
+        let mut length = 0.0;
+
+        let mut sub_path = 0;
+        let mut start = Point::ZERO;
+        let mut current = Point::ZERO;
+
+        let mut push = |kind: SegmentKind, closes: bool, sub_path: usize| {
+            let segment_length = match kind {
+                SegmentKind::Line(start, end) => (end.x() - start.x()).hypot(end.y() - start.y()),
+                SegmentKind::Curve(curve) => curve_length(&curve, MAX_LENGTH_ERROR),
+            };
+
+            segments.push(Segment {
+                kind,
+                offset: length,
+                length: segment_length,
+                sub_path,
+                closes,
+            });
+            length += segment_length;
+        };
+
+        for path_like in PathLike::simplify(path) {
+            match path_like {
+                PathLike::Move(point) => {
+                    sub_path += 1;
+                    start = point;
+                    current = point;
+                }
+                PathLike::Line(point) => {
+                    push(SegmentKind::Line(current, point), false, sub_path);
+                    current = point;
+                }
+                PathLike::CurveTo(end, c_s, c_e) => {
+                    let curve = Curve::from_points(
+                        point_to_coord2(&current),
+                        (point_to_coord2(&c_s), point_to_coord2(&c_e)),
+                        point_to_coord2(&end),
+                    );
+                    push(SegmentKind::Curve(curve), false, sub_path);
+                    current = end;
+                }
+                PathLike::Close => {
+                    push(SegmentKind::Line(current, start), true, sub_path);
+                    current = start;
+                }
+                _ => unreachable!("the path is simplified"),
+            }
+        }
+
+        PathMeasure { segments, length }
+    }
+
+    /// Total length of the path.
+    pub fn length(&self) -> f64 {
+        self.length
+    }
+
+    /// Returns the point at `distance` from the start of the path, the distance is clamped to the
+    /// length of the path. Returns `None` if the path has no segments.
+    pub fn point_at_length(&self, distance: f64) -> Option<Point> {
+        let (segment, t) = self.position_at_length(distance)?;
+
+        Some(segment.point_at(t))
+    }
+
+    /// Returns the point at `percentage` (from `0.0` to `1.0`) of the length of the path.
+    pub fn point_at(&self, percentage: f64) -> Option<Point> {
+        self.point_at_length(percentage * self.length)
+    }
+
+    /// Returns the normalised direction of the path at `distance` from the start of the path, the
+    /// distance is clamped to the length of the path. Returns `None` if the path has no segments
+    /// and [`Point::ZERO`] if the path has no direction at this point.
+    pub fn tangent_at_length(&self, distance: f64) -> Option<Point> {
+        let (segment, t) = self.position_at_length(distance)?;
+        let tangent = segment.tangent_at(t);
+
+        let length = tangent.x().hypot(tangent.y());
+        match length > 0.0 {
+            true => Some(tangent / length),
+            false => Some(Point::ZERO),
+        }
+    }
+
+    /// Returns the direction at `percentage` (from `0.0` to `1.0`) of the length of the path, see
+    /// [`PathMeasure::tangent_at_length`].
+    pub fn tangent_at(&self, percentage: f64) -> Option<Point> {
+        self.tangent_at_length(percentage * self.length)
+    }
+
+    /// The segment and the position on the segment at `distance` from the start of the path.
+    fn position_at_length(&self, distance: f64) -> Option<(&Segment, f64)> {
+        let distance = distance.clamp(0.0, self.length);
+
+        let segment = self
+            .segments
+            .iter()
+            .filter(|segment| segment.length > 0.0)
+            .find(|segment| distance <= segment.offset + segment.length)
+            .or_else(|| self.segments.last())?;
+
+        Some((
+            segment,
+            segment.position_at_length(distance - segment.offset),
+        ))
+    }
+
+    /// Returns the part of the path between `start` and `end`, both are percentages from `0.0` to
+    /// `1.0` of the length of the path. The result is empty if `start >= end`.
+    ///
+    /// Like "Trim Paths" in After Effects, a closed sub-path stays closed if it's visible
+    /// completely.
+    pub fn trim(&self, start: f64, end: f64) -> Vec<PathLike> {
+        let start = start.clamp(0.0, 1.0) * self.length;
+        let end = end.clamp(0.0, 1.0) * self.length;
+
+        let mut path = Vec::new();
+        if start >= end {
+            return path;
+        }
+
+        // sub-path of the last visible segment and if it's visible from the start of the sub-path
+        let mut last: Option<(usize, bool)> = None;
+
+        for (index, segment) in self.segments.iter().enumerate() {
+            let segment_end = segment.offset + segment.length;
+            let continues = matches!(last, Some((sub_path, _)) if sub_path == segment.sub_path);
+
+            let visible = match segment.length > 0.0 {
+                true => segment_end > start && segment.offset < end,
+                // a close without length can only close an already visible sub-path
+                false => segment.closes && continues && segment.offset <= end,
+            };
+            if !visible {
+                continue;
+            }
+
+            let t_start = segment.position_at_length(start - segment.offset);
+            let t_end = segment.position_at_length(end - segment.offset);
+
+            if !continues {
+                let starts_sub_path = t_start == 0.0
+                    && (index == 0 || self.segments[index - 1].sub_path != segment.sub_path);
+
+                path.push(PathLike::Move(segment.point_at(t_start)));
+                last = Some((segment.sub_path, starts_sub_path));
+            }
+
+            match last {
+                Some((_, true)) if segment.closes && end >= segment_end => {
+                    path.push(PathLike::Close)
+                }
+                _ => path.push(segment.section(t_start, t_end)),
+            }
+        }
+
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+
+    // square from (0, 0) to (10, 10) with a length of 40
+    fn square() -> Vec<PathLike> {
+        vec![
+            PathLike::Move(Point::ZERO),
+            PathLike::Line(Point::new(10.0, 0.0)),
+            PathLike::Line(Point::new(10.0, 10.0)),
+            PathLike::Line(Point::new(0.0, 10.0)),
+            PathLike::Close,
+        ]
+    }
+
+    #[test]
+    fn length_of_lines() {
+        assert_abs_diff_eq!(PathMeasure::new(&square()).length(), 40.0);
+
+        let path = vec![
+            PathLike::Move(Point::ZERO),
+            PathLike::LineRelative(Point::new(3.0, 4.0)),
+            PathLike::Move(Point::new(100.0, 100.0)),
+            PathLike::Line(Point::new(100.0, 105.0)),
+        ];
+        assert_abs_diff_eq!(PathMeasure::new(&path).length(), 10.0);
+    }
+
+    #[test]
+    fn length_of_curves() {
+        // quarter circle with the radius 10 approximated by a cubic curve
+        let k = 10.0 * 0.5522847498;
+        let path = vec![
+            PathLike::Move(Point::new(10.0, 0.0)),
+            PathLike::CurveTo(
+                Point::new(0.0, 10.0),
+                Point::new(10.0, k),
+                Point::new(k, 10.0),
+            ),
+        ];
+        let measure = PathMeasure::new(&path);
+
+        assert_abs_diff_eq!(measure.length(), 5.0 * std::f64::consts::PI, epsilon = 0.01);
+
+        let middle = measure.point_at(0.5).unwrap();
+        let diagonal = 10.0 * std::f64::consts::FRAC_1_SQRT_2;
+        assert_abs_diff_eq!(middle, Point::new(diagonal, diagonal), epsilon = 0.01);
+    }
+
+    #[test]
+    fn point_at() {
+        let measure = PathMeasure::new(&square());
+
+        assert_abs_diff_eq!(measure.point_at(0.0).unwrap(), Point::ZERO);
+        assert_abs_diff_eq!(measure.point_at(0.3).unwrap(), Point::new(10.0, 2.0));
+        assert_abs_diff_eq!(measure.point_at(0.9).unwrap(), Point::new(0.0, 4.0));
+        assert_abs_diff_eq!(measure.point_at(2.0).unwrap(), Point::ZERO);
+
+        assert_eq!(PathMeasure::new(&[]).point_at(0.5), None);
+    }
+
+    #[test]
+    fn tangent_at() {
+        let measure = PathMeasure::new(&square());
+
+        assert_abs_diff_eq!(measure.tangent_at(0.0).unwrap(), Point::new(1.0, 0.0));
+        assert_abs_diff_eq!(measure.tangent_at(0.3).unwrap(), Point::new(0.0, 1.0));
+        assert_abs_diff_eq!(measure.tangent_at(0.6).unwrap(), Point::new(-1.0, 0.0));
+        assert_abs_diff_eq!(measure.tangent_at(0.9).unwrap(), Point::new(0.0, -1.0));
+
+        assert_eq!(PathMeasure::new(&[]).tangent_at(0.5), None);
+    }
+
+    #[test]
+    fn tangent_of_curves() {
+        // the control points are on the start and end point
+        let path = vec![
+            PathLike::Move(Point::ZERO),
+            PathLike::CurveTo(Point::new(10.0, 10.0), Point::ZERO, Point::new(10.0, 10.0)),
+        ];
+        let diagonal = std::f64::consts::FRAC_1_SQRT_2;
+
+        let measure = PathMeasure::new(&path);
+        assert_abs_diff_eq!(
+            measure.tangent_at(0.0).unwrap(),
+            Point::new(diagonal, diagonal),
+            epsilon = 0.0001
+        );
+        assert_abs_diff_eq!(
+            measure.tangent_at(0.5).unwrap(),
+            Point::new(diagonal, diagonal),
+            epsilon = 0.0001
+        );
+    }
+
+    #[test]
+    fn trim_keeps_the_whole_path() {
+        let trimmed = PathMeasure::new(&square()).trim(0.0, 1.0);
+
+        assert_eq!(trimmed, square());
+    }
+
+    #[test]
+    fn trim_part_of_the_path() {
+        let measure = PathMeasure::new(&square());
+
+        assert_eq!(
+            measure.trim(0.125, 0.6),
+            vec![
+                PathLike::Move(Point::new(5.0, 0.0)),
+                PathLike::Line(Point::new(10.0, 0.0)),
+                PathLike::Line(Point::new(10.0, 10.0)),
+                PathLike::Line(Point::new(6.0, 10.0)),
+            ]
+        );
+
+        // the close is trimmed as a line
+        assert_eq!(
+            measure.trim(0.0, 0.875),
+            vec![
+                PathLike::Move(Point::ZERO),
+                PathLike::Line(Point::new(10.0, 0.0)),
+                PathLike::Line(Point::new(10.0, 10.0)),
+                PathLike::Line(Point::new(0.0, 10.0)),
+                PathLike::Line(Point::new(0.0, 5.0)),
+            ]
+        );
+
+        assert!(measure.trim(0.5, 0.5).is_empty());
+        assert!(measure.trim(0.8, 0.2).is_empty());
+    }
+
+    #[test]
+    fn trim_multiple_sub_paths() {
+        let path = vec![
+            PathLike::Move(Point::ZERO),
+            PathLike::Line(Point::new(10.0, 0.0)),
+            PathLike::Move(Point::new(0.0, 10.0)),
+            PathLike::Line(Point::new(10.0, 10.0)),
+        ];
+
+        assert_eq!(
+            PathMeasure::new(&path).trim(0.25, 0.75),
+            vec![
+                PathLike::Move(Point::new(5.0, 0.0)),
+                PathLike::Line(Point::new(10.0, 0.0)),
+                PathLike::Move(Point::new(0.0, 10.0)),
+                PathLike::Line(Point::new(5.0, 10.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn trim_curves() {
+        let path = vec![
+            PathLike::Move(Point::ZERO),
+            PathLike::CurveTo(
+                Point::new(30.0, 0.0),
+                Point::new(10.0, 10.0),
+                Point::new(20.0, -10.0),
+            ),
+        ];
+        let measure = PathMeasure::new(&path);
+        let trimmed = PathMeasure::new(&measure.trim(0.2, 0.7));
+
+        assert_abs_diff_eq!(trimmed.length(), measure.length() * 0.5, epsilon = 0.001);
+        assert_abs_diff_eq!(
+            trimmed.point_at(0.0).unwrap(),
+            measure.point_at(0.2).unwrap(),
+            epsilon = 0.001
+        );
+        assert_abs_diff_eq!(
+            trimmed.point_at(1.0).unwrap(),
+            measure.point_at(0.7).unwrap(),
+            epsilon = 0.001
+        );
+    }
+}
@@ -0,0 +1,363 @@
+use flo_curves::bezier::{curve_length, BezierCurve, BezierCurveFactory, Curve};
+use flo_curves::Coord2;
+
+use crate::holder::likes::path_like::PathLike;
+use crate::holder::likes::utils::{coord2_to_point, point_to_coord2};
+use crate::point::Point;
+
+/// Maximum error when estimating the length of a segment.
+const MAX_LENGTH_ERROR: f64 = 0.001;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+struct Cubic {
+    start: Point,
+    control_start: Point,
+    control_end: Point,
+    end: Point,
+}
+
+impl Cubic {
+    fn line(start: Point, end: Point) -> Self {
+        Cubic {
+            start,
+            control_start: start + (end - start) / 3.0,
+            control_end: start + (end - start) * (2.0 / 3.0),
+            end,
+        }
+    }
+
+    fn point(point: Point) -> Self {
+        Self::line(point, point)
+    }
+
+    fn to_curve(self) -> Curve<Coord2> {
+        Curve::from_points(
+            point_to_coord2(&self.start),
+            (
+                point_to_coord2(&self.control_start),
+                point_to_coord2(&self.control_end),
+            ),
+            point_to_coord2(&self.end),
+        )
+    }
+
+    fn from_curve(curve: &impl BezierCurve<Point = Coord2>) -> Self {
+        let (control_start, control_end) = curve.control_points();
+
+        Cubic {
+            start: coord2_to_point(&curve.start_point()),
+            control_start: coord2_to_point(&control_start),
+            control_end: coord2_to_point(&control_end),
+            end: coord2_to_point(&curve.end_point()),
+        }
+    }
+
+    fn length(&self) -> f64 {
+        curve_length(&self.to_curve(), MAX_LENGTH_ERROR)
+    }
+
+    /// Splits the segment in the middle.
+    fn split(&self) -> (Cubic, Cubic) {
+        let curve = self.to_curve();
+
+        (
+            Cubic::from_curve(&curve.section(0.0, 0.5)),
+            Cubic::from_curve(&curve.section(0.5, 1.0)),
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+struct SubPath {
+    start: Point,
+    segments: Vec<Cubic>,
+    closed: bool,
+}
+
+impl SubPath {
+    fn new(start: Point) -> Self {
+        SubPath {
+            start,
+            segments: Vec::new(),
+            closed: false,
+        }
+    }
+
+    /// A sub-path without length at the start of `other`.
+    fn point_of(other: &SubPath) -> Self {
+        SubPath {
+            closed: other.closed,
+            ..SubPath::new(other.start)
+        }
+    }
+
+    /// Splits the longest segments until the sub-path has `count` segments.
+    fn subdivide(&mut self, count: usize) {
+        if self.segments.is_empty() && count > 0 {
+            self.segments.push(Cubic::point(self.start));
+        }
+
+        let mut lengths: Vec<f64> = self.segments.iter().map(Cubic::length).collect();
+
+        while self.segments.len() < count {
+            let (index, length) = lengths.iter().copied().enumerate().fold(
+                (0, f64::MIN),
+                |max, current| match current.1 > max.1 {
+                    true => current,
+                    false => max,
+                },
+            );
+
+            let (first, second) = self.segments[index].split();
+            self.segments.splice(index..=index, [first, second]);
+            lengths.splice(index..=index, [length / 2.0, length / 2.0]);
+        }
+    }
+
+    /// Rotates the segments of a closed sub-path so that its start points are as close as
+    /// possible to the ones of `other`.
+    fn align_to(&mut self, other: &SubPath) {
+        let count = self.segments.len();
+        if count == 0 || count != other.segments.len() {
+            return;
+        }
+
+        let distance = |offset: usize| -> f64 {
+            (0..count)
+                .map(|i| {
+                    let delta = self.segments[(i + offset) % count].start - other.segments[i].start;
+                    delta.x() * delta.x() + delta.y() * delta.y()
+                })
+                .sum()
+        };
+
+        let best = (0..count)
+            .min_by(|a, b| distance(*a).total_cmp(&distance(*b)))
+            .unwrap_or(0);
+
+        self.segments.rotate_left(best);
+        self.start = self.segments[0].start;
+    }
+}
+
+/// Splits the path into sub-paths which only contain cubic curves, the line of a
+/// [`PathLike::Close`] is added as own segment.
+fn sub_paths(path: &[PathLike]) -> Vec<SubPath> {
+    let mut sub_paths: Vec<SubPath> = Vec::new();
+    let mut current = Point::ZERO;
+
+    for path_like in PathLike::simplify(path) {
+        if let PathLike::Move(point) = path_like {
+            sub_paths.push(SubPath::new(point));
+            current = point;
+            continue;
+        }
+
+        // after a close the next sub-path starts at the start of the closed one
+        match sub_paths.last() {
+            Some(sub_path) if !sub_path.closed => (),
+            last => {
+                let start = last.map(|sub_path| sub_path.start).unwrap_or(Point::ZERO);
+                sub_paths.push(SubPath::new(start));
+            }
+        }
+        let sub_path = sub_paths.last_mut().unwrap();
+
+        match path_like {
+            PathLike::Line(point) => {
+                sub_path.segments.push(Cubic::line(current, point));
+                current = point;
+            }
+            PathLike::CurveTo(end, control_start, control_end) => {
+                sub_path.segments.push(Cubic {
+                    start: current,
+                    control_start,
+                    control_end,
+                    end,
+                });
+                current = end;
+            }
+            PathLike::Close => {
+                if current != sub_path.start {
+                    sub_path.segments.push(Cubic::line(current, sub_path.start));
+                }
+                sub_path.closed = true;
+                current = sub_path.start;
+            }
+            _ => unreachable!("the path is simplified"),
+        }
+    }
+
+    sub_paths
+}
+
+fn lerp(start: Point, end: Point, t: f64) -> Point {
+    start + (end - start) * t
+}
+
+/// Interpolates between two paths, e.g. to morph a circle into a rect.
+///
+/// Both paths are normalised to the same commands: all commands are converted into cubic curves,
+/// missing sub-paths are added as a point at the start of the other sub-path and the longest
+/// segments are split until both sub-paths have the same number of segments. Closed sub-paths are
+/// rotated so that their start points match as close as possible.
+///
+/// A sub-path stays only closed if it's closed in both paths, otherwise the line of the close is
+/// kept as normal segment.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct PathMorph {
+    start: Vec<SubPath>,
+    end: Vec<SubPath>,
+}
+
+impl PathMorph {
+    pub fn new(start: &[PathLike], end: &[PathLike]) -> Self {
+        let mut start = sub_paths(start);
+        let mut end = sub_paths(end);
+
+        for index in 0..start.len().max(end.len()) {
+            match (start.get(index), end.get(index)) {
+                (Some(sub_path), None) => end.push(SubPath::point_of(sub_path)),
+                (None, Some(sub_path)) => start.push(SubPath::point_of(sub_path)),
+                _ => (),
+            }
+
+            let count = start[index].segments.len().max(end[index].segments.len());
+            start[index].subdivide(count);
+            end[index].subdivide(count);
+
+            if start[index].closed && end[index].closed {
+                end[index].align_to(&start[index]);
+            }
+        }
+
+        PathMorph { start, end }
+    }
+
+    /// Returns the path at `t`, `0.0` is the start path and `1.0` is the end path. Values outside
+    /// of this range extrapolate the paths.
+    pub fn interpolate(&self, t: f64) -> Vec<PathLike> {
+        let mut path = Vec::new();
+
+        for (start, end) in self.start.iter().zip(self.end.iter()) {
+            path.push(PathLike::Move(lerp(start.start, end.start, t)));
+
+            for (s, e) in start.segments.iter().zip(end.segments.iter()) {
+                path.push(PathLike::CurveTo(
+                    lerp(s.end, e.end, t),
+                    lerp(s.control_start, e.control_start, t),
+                    lerp(s.control_end, e.control_end, t),
+                ));
+            }
+
+            if start.closed && end.closed {
+                path.push(PathLike::Close);
+            }
+        }
+
+        path
+    }
+
+    /// The normalised start and end path, both have the same commands.
+    pub fn normalized(&self) -> (Vec<PathLike>, Vec<PathLike>) {
+        (self.interpolate(0.0), self.interpolate(1.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+    use crate::holder::likes::path_measure::PathMeasure;
+
+    fn square(position: Point, size: f64) -> Vec<PathLike> {
+        vec![
+            PathLike::Move(position),
+            PathLike::Line(position + Point::new(size, 0.0)),
+            PathLike::Line(position + Point::new(size, size)),
+            PathLike::Line(position + Point::new(0.0, size)),
+            PathLike::Close,
+        ]
+    }
+
+    fn triangle() -> Vec<PathLike> {
+        vec![
+            PathLike::Move(Point::new(10.0, 0.0)),
+            PathLike::Line(Point::new(20.0, 20.0)),
+            PathLike::Line(Point::new(0.0, 20.0)),
+            PathLike::Close,
+        ]
+    }
+
+    fn assert_same_shape(a: &[PathLike], b: &[PathLike]) {
+        let (a, b) = (PathMeasure::new(a), PathMeasure::new(b));
+        assert_abs_diff_eq!(a.length(), b.length(), epsilon = 0.01);
+
+        let (a_min, a_max) = PathLike::bounding_box(&a.trim(0.0, 1.0));
+        let (b_min, b_max) = PathLike::bounding_box(&b.trim(0.0, 1.0));
+        assert_abs_diff_eq!(a_min, b_min, epsilon = 0.01);
+        assert_abs_diff_eq!(a_max, b_max, epsilon = 0.01);
+    }
+
+    #[test]
+    fn normalizes_to_the_same_commands() {
+        let morph = PathMorph::new(&triangle(), &square(Point::ZERO, 20.0));
+        let (start, end) = morph.normalized();
+
+        assert_eq!(start.len(), end.len());
+        assert!(start.iter().zip(end.iter()).all(|(a, b)| a.type_equal(b)));
+
+        // move, four segments and close
+        assert_eq!(start.len(), 6);
+        assert_same_shape(&start, &triangle());
+        assert_same_shape(&end, &square(Point::ZERO, 20.0));
+    }
+
+    #[test]
+    fn interpolates_point_by_point() {
+        let morph = PathMorph::new(
+            &square(Point::ZERO, 10.0),
+            &square(Point::new(10.0, 20.0), 20.0),
+        );
+
+        let middle = morph.interpolate(0.5);
+        let (min, max) = PathLike::bounding_box(&middle);
+        assert_abs_diff_eq!(min, Point::new(5.0, 10.0), epsilon = 0.0001);
+        assert_abs_diff_eq!(max, Point::new(20.0, 25.0), epsilon = 0.0001);
+        assert_eq!(middle.last(), Some(&PathLike::Close));
+    }
+
+    #[test]
+    fn adds_missing_sub_paths() {
+        let mut two_squares = square(Point::ZERO, 10.0);
+        two_squares.extend(square(Point::new(20.0, 0.0), 10.0));
+
+        let morph = PathMorph::new(&square(Point::ZERO, 10.0), &two_squares);
+        let (start, end) = morph.normalized();
+
+        assert_eq!(start.len(), end.len());
+        assert_same_shape(&start, &square(Point::ZERO, 10.0));
+        assert_same_shape(&end, &two_squares);
+    }
+
+    #[test]
+    fn aligns_closed_sub_paths() {
+        let mut rotated = square(Point::ZERO, 10.0);
+        rotated[0] = PathLike::Move(Point::new(10.0, 10.0));
+        rotated[1] = PathLike::Line(Point::new(0.0, 10.0));
+        rotated[2] = PathLike::Line(Point::ZERO);
+        rotated[3] = PathLike::Line(Point::new(10.0, 0.0));
+
+        let morph = PathMorph::new(&square(Point::ZERO, 10.0), &rotated);
+
+        // the same square with a different start doesn't move
+        assert_eq!(morph.interpolate(0.5), morph.interpolate(0.0));
+    }
+}
@@ -0,0 +1,450 @@
+use thiserror::Error;
+
+use crate::holder::likes::path_like::PathLike;
+use crate::point::Point;
+
+/// Error while parsing svg path data, all positions are byte offsets into the path data.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum PathParseError {
+    #[error("unexpected character `{character}` at position {position}")]
+    UnexpectedCharacter { character: char, position: usize },
+
+    #[error("expected a number at position {position}")]
+    ExpectedNumber { position: usize },
+
+    #[error("expected a flag (`0` or `1`) at position {position}")]
+    ExpectedFlag { position: usize },
+
+    #[error("path data must start with a move command, found `{command}` at position {position}")]
+    MissingMove { command: char, position: usize },
+
+    #[error("expected a command at position {position}")]
+    ExpectedCommand { position: usize },
+
+    #[error("expected the end of the command at position {position}")]
+    ExpectedEnd { position: usize },
+}
+
+const COMMANDS: &[u8] = b"MmLlHhVvCcSsQqTtAaZz";
+
+struct Parser<'a> {
+    data: &'a [u8],
+    position: usize,
+
+    current_point: Point,
+    sub_path_start: Point,
+}
+
+impl<'a> Parser<'a> {
+    fn new(data: &'a str) -> Self {
+        Parser {
+            data: data.as_bytes(),
+            position: 0,
+            current_point: Point::ZERO,
+            sub_path_start: Point::ZERO,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.data.get(self.position).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r' | b'\x0C')) {
+            self.position += 1;
+        }
+    }
+
+    fn skip_separator(&mut self) {
+        self.skip_whitespace();
+        if self.peek() == Some(b',') {
+            self.position += 1;
+            self.skip_whitespace();
+        }
+    }
+
+    fn unexpected_character(&self) -> PathParseError {
+        // the data is always valid utf-8 because it's created from a `&str`
+        let rest = std::str::from_utf8(&self.data[self.position..]).unwrap_or_default();
+
+        PathParseError::UnexpectedCharacter {
+            character: rest.chars().next().unwrap_or_default(),
+            position: self.position,
+        }
+    }
+
+    /// Returns `true` if the next token starts a number, used for implicit repeated commands.
+    fn next_is_number(&mut self) -> bool {
+        self.skip_separator();
+        matches!(self.peek(), Some(b'0'..=b'9' | b'.' | b'-' | b'+'))
+    }
+
+    fn number(&mut self) -> Result<f64, PathParseError> {
+        self.skip_separator();
+
+        let start = self.position;
+        let digits = |parser: &mut Self| {
+            let start = parser.position;
+            while matches!(parser.peek(), Some(b'0'..=b'9')) {
+                parser.position += 1;
+            }
+            parser.position > start
+        };
+
+        if matches!(self.peek(), Some(b'-' | b'+')) {
+            self.position += 1;
+        }
+        let mut has_digits = digits(self);
+        if self.peek() == Some(b'.') {
+            self.position += 1;
+            has_digits |= digits(self);
+        }
+        if !has_digits {
+            self.position = start;
+            return Err(PathParseError::ExpectedNumber { position: start });
+        }
+
+        // only consume the exponent if it's valid, otherwise the `e` belongs to the next token
+        if matches!(self.peek(), Some(b'e' | b'E')) {
+            let before_exponent = self.position;
+            self.position += 1;
+            if matches!(self.peek(), Some(b'-' | b'+')) {
+                self.position += 1;
+            }
+            if !digits(self) {
+                self.position = before_exponent;
+            }
+        }
+
+        // the slice only contains ascii characters
+        std::str::from_utf8(&self.data[start..self.position])
+            .ok()
+            .and_then(|number| number.parse().ok())
+            .ok_or(PathParseError::ExpectedNumber { position: start })
+    }
+
+    fn point(&mut self) -> Result<Point, PathParseError> {
+        Ok(Point::new(self.number()?, self.number()?))
+    }
+
+    fn flag(&mut self) -> Result<bool, PathParseError> {
+        self.skip_separator();
+
+        let flag = match self.peek() {
+            Some(b'0') => false,
+            Some(b'1') => true,
+            _ => {
+                return Err(PathParseError::ExpectedFlag {
+                    position: self.position,
+                })
+            }
+        };
+        self.position += 1;
+
+        Ok(flag)
+    }
+
+    fn command(&mut self, command: u8) -> Result<PathLike, PathParseError> {
+        let relative = command.is_ascii_lowercase();
+        let origin = match relative {
+            true => self.current_point,
+            false => Point::ZERO,
+        };
+
+        let path_like = match (command.to_ascii_uppercase(), relative) {
+            (b'M', false) => PathLike::Move(self.point()?),
+            (b'M', true) => PathLike::MoveRelative(self.point()?),
+            (b'L', false) => PathLike::Line(self.point()?),
+            (b'L', true) => PathLike::LineRelative(self.point()?),
+            (b'H', false) => PathLike::Line(Point::new(self.number()?, self.current_point.y())),
+            (b'H', true) => PathLike::LineRelative(Point::new(self.number()?, 0.0)),
+            (b'V', false) => PathLike::Line(Point::new(self.current_point.x(), self.number()?)),
+            (b'V', true) => PathLike::LineRelative(Point::new(0.0, self.number()?)),
+            (b'C', _) => {
+                let c_s = self.point()?;
+                let c_e = self.point()?;
+                let end = self.point()?;
+
+                match relative {
+                    true => PathLike::CurveToRelative(end, c_s, c_e),
+                    false => PathLike::CurveTo(end, c_s, c_e),
+                }
+            }
+            (b'S', _) => {
+                let c_e = self.point()?;
+                let end = self.point()?;
+
+                match relative {
+                    true => PathLike::SmoothCurveToRelative(end, c_e),
+                    false => PathLike::SmoothCurveTo(end, c_e),
+                }
+            }
+            (b'Q', _) => {
+                let control = self.point()?;
+                let end = self.point()?;
+
+                match relative {
+                    true => PathLike::QuadToRelative(end, control),
+                    false => PathLike::QuadTo(end, control),
+                }
+            }
+            (b'T', false) => PathLike::SmoothQuadTo(self.point()?),
+            (b'T', true) => PathLike::SmoothQuadToRelative(self.point()?),
+            (b'A', _) => {
+                let radius = self.point()?;
+                let x_axis_rotation = self.number()?;
+                let large_arc = self.flag()?;
+                let sweep = self.flag()?;
+                let end = self.point()?;
+
+                match relative {
+                    true => PathLike::ArcRelative {
+                        end,
+                        radius,
+                        x_axis_rotation,
+                        large_arc,
+                        sweep,
+                    },
+                    false => PathLike::Arc {
+                        end,
+                        radius,
+                        x_axis_rotation,
+                        large_arc,
+                        sweep,
+                    },
+                }
+            }
+            (b'Z', _) => PathLike::Close,
+            _ => unreachable!("only called with valid commands"),
+        };
+
+        self.current_point = match path_like.end_point() {
+            Some(end) => origin + end,
+            None => self.sub_path_start,
+        };
+        if matches!(path_like, PathLike::Move(_) | PathLike::MoveRelative(_)) {
+            self.sub_path_start = self.current_point;
+        }
+
+        Ok(path_like)
+    }
+
+    fn parse(mut self) -> Result<Vec<PathLike>, PathParseError> {
+        let mut path = Vec::new();
+
+        loop {
+            self.skip_whitespace();
+
+            let command = match self.peek() {
+                Some(command) if COMMANDS.contains(&command) => command,
+                Some(_) => return Err(self.unexpected_character()),
+                None => break,
+            };
+
+            if path.is_empty() && !matches!(command, b'M' | b'm') {
+                return Err(PathParseError::MissingMove {
+                    command: command as char,
+                    position: self.position,
+                });
+            }
+            self.position += 1;
+
+            path.push(self.command(command)?);
+
+            // a command can be repeated without the letter, a repeated move is a line
+            if !matches!(command, b'Z' | b'z') {
+                let repeated = match command {
+                    b'M' => b'L',
+                    b'm' => b'l',
+                    command => command,
+                };
+
+                while self.next_is_number() {
+                    path.push(self.command(repeated)?);
+                }
+            }
+        }
+
+        Ok(path)
+    }
+}
+
+/// Parses svg path data, see [`PathLike::parse`].
+pub(crate) fn parse(data: &str) -> Result<Vec<PathLike>, PathParseError> {
+    Parser::new(data).parse()
+}
+
+/// Parses a single command, the command doesn't have to be a move.
+pub(crate) fn parse_single(data: &str) -> Result<PathLike, PathParseError> {
+    let mut parser = Parser::new(data);
+    parser.skip_whitespace();
+
+    let command = match parser.peek() {
+        Some(command) if COMMANDS.contains(&command) => command,
+        Some(_) => return Err(parser.unexpected_character()),
+        None => {
+            return Err(PathParseError::ExpectedCommand {
+                position: parser.position,
+            })
+        }
+    };
+    parser.position += 1;
+
+    let path_like = parser.command(command)?;
+
+    parser.skip_whitespace();
+    match parser.peek() {
+        None => Ok(path_like),
+        Some(_) => Err(PathParseError::ExpectedEnd {
+            position: parser.position,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_all_commands() {
+        let path = parse("M 10 10 L 20,10 H 30 V 20 C 30 30, 20 30, 20 20 S 10 10, 10 20 Q 0 20, 0 10 T 5 5 A 5 5 45 0 1 10 10 Z").unwrap();
+
+        assert_eq!(
+            path,
+            vec![
+                PathLike::Move(Point::new(10.0, 10.0)),
+                PathLike::Line(Point::new(20.0, 10.0)),
+                PathLike::Line(Point::new(30.0, 10.0)),
+                PathLike::Line(Point::new(30.0, 20.0)),
+                PathLike::CurveTo(
+                    Point::new(20.0, 20.0),
+                    Point::new(30.0, 30.0),
+                    Point::new(20.0, 30.0)
+                ),
+                PathLike::SmoothCurveTo(Point::new(10.0, 20.0), Point::new(10.0, 10.0)),
+                PathLike::QuadTo(Point::new(0.0, 10.0), Point::new(0.0, 20.0)),
+                PathLike::SmoothQuadTo(Point::new(5.0, 5.0)),
+                PathLike::Arc {
+                    end: Point::new(10.0, 10.0),
+                    radius: Point::new(5.0, 5.0),
+                    x_axis_rotation: 45.0,
+                    large_arc: false,
+                    sweep: true,
+                },
+                PathLike::Close,
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_relative_commands() {
+        let path = parse("m 10 10 l 10 0 h 5 v 5 z").unwrap();
+
+        assert_eq!(
+            path,
+            vec![
+                PathLike::MoveRelative(Point::new(10.0, 10.0)),
+                PathLike::LineRelative(Point::new(10.0, 0.0)),
+                PathLike::LineRelative(Point::new(5.0, 0.0)),
+                PathLike::LineRelative(Point::new(0.0, 5.0)),
+                PathLike::Close,
+            ]
+        );
+    }
+
+    #[test]
+    fn horizontal_and_vertical_lines_use_the_current_point() {
+        let path = parse("M 10 10 l 5 5 H 30 z V 0").unwrap();
+
+        assert_eq!(path[2], PathLike::Line(Point::new(30.0, 15.0)));
+        // after a close the current point is the start of the sub path
+        assert_eq!(path[4], PathLike::Line(Point::new(10.0, 0.0)));
+    }
+
+    #[test]
+    fn repeated_commands() {
+        let path = parse("M 0 0 10 10 20 0 l 1 1 2 2").unwrap();
+
+        assert_eq!(
+            path,
+            vec![
+                PathLike::Move(Point::ZERO),
+                PathLike::Line(Point::new(10.0, 10.0)),
+                PathLike::Line(Point::new(20.0, 0.0)),
+                PathLike::LineRelative(Point::ONE),
+                PathLike::LineRelative(Point::new(2.0, 2.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn compact_numbers_and_flags() {
+        let path = parse("M1.5.5-2e1,3E-1a1 1 0 0110 10").unwrap();
+
+        assert_eq!(
+            path,
+            vec![
+                PathLike::Move(Point::new(1.5, 0.5)),
+                PathLike::Line(Point::new(-20.0, 0.3)),
+                PathLike::ArcRelative {
+                    end: Point::new(10.0, 10.0),
+                    radius: Point::ONE,
+                    x_axis_rotation: 0.0,
+                    large_arc: false,
+                    sweep: true,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn empty_path() {
+        assert_eq!(parse("  "), Ok(vec![]));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            parse("L 10 10"),
+            Err(PathParseError::MissingMove {
+                command: 'L',
+                position: 0
+            })
+        );
+        assert_eq!(
+            parse("M 10 10 X"),
+            Err(PathParseError::UnexpectedCharacter {
+                character: 'X',
+                position: 8
+            })
+        );
+        assert_eq!(
+            parse("M 10 10 L 20"),
+            Err(PathParseError::ExpectedNumber { position: 12 })
+        );
+        assert_eq!(
+            parse("M 10 10 L 20 ."),
+            Err(PathParseError::ExpectedNumber { position: 13 })
+        );
+        assert_eq!(
+            parse("M 0 0 A 1 1 0 2 1 10 10"),
+            Err(PathParseError::ExpectedFlag { position: 14 })
+        );
+    }
+
+    #[test]
+    fn single_command() {
+        assert_eq!(
+            parse_single(" L 10 10 "),
+            Ok(PathLike::Line(Point::new(10.0, 10.0)))
+        );
+        assert_eq!(
+            parse_single(""),
+            Err(PathParseError::ExpectedCommand { position: 0 })
+        );
+        assert_eq!(
+            parse_single("L 10 10 L 20 20"),
+            Err(PathParseError::ExpectedEnd { position: 8 })
+        );
+    }
+}
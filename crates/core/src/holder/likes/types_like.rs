@@ -1,5 +1,6 @@
 use crate::holder::image_holder::ImageHolder;
 use crate::holder::svg_holder::SvgHolder;
+use crate::holder::text_holder::TextHolder;
 use crate::holder::transform::{Transform, TransformError, TransformLogic};
 
 #[derive(Debug)]
@@ -8,6 +9,9 @@ use crate::holder::transform::{Transform, TransformError, TransformLogic};
 pub enum TypesLike {
     Svg(SvgHolder),
     Image(ImageHolder),
+    Text(TextHolder),
+    /// A nested composition, the image is replaced by the current frame of the composition
+    Composition(ImageHolder),
 }
 
 impl TypesLike {
@@ -24,7 +28,9 @@ impl TransformLogic for TypesLike {
     fn transform(&mut self, transformation: &Transform) -> Result<(), TransformError> {
         match self {
             TypesLike::Svg(svg) => svg.transform(transformation),
-            TypesLike::Image(_) => todo!(),
+            TypesLike::Image(image) => image.transform(transformation),
+            TypesLike::Text(text) => text.transform(transformation),
+            TypesLike::Composition(image) => image.transform(transformation),
         }
     }
 
@@ -35,7 +41,9 @@ impl TransformLogic for TypesLike {
     ) -> Result<(), TransformError> {
         match self {
             TypesLike::Svg(svg) => svg.transform_by_id(id, transformation),
-            TypesLike::Image(_) => todo!(),
+            TypesLike::Image(image) => image.transform_by_id(id, transformation),
+            TypesLike::Text(text) => text.transform_by_id(id, transformation),
+            TypesLike::Composition(image) => image.transform_by_id(id, transformation),
         }
     }
 }
@@ -3,8 +3,10 @@ pub mod image_holder;
 pub mod likes;
 pub mod object;
 pub mod stroke;
+pub mod svg_group;
 pub mod svg_holder;
 pub mod svg_item;
+pub mod text_holder;
 pub mod transform;
 
 pub mod utils;
@@ -1,12 +1,11 @@
 use std::collections::HashMap;
 
 use resvg::tiny_skia::Pixmap;
-use resvg::usvg::{AspectRatio, NodeExt, Size, Tree, ViewBox};
+use resvg::usvg::{Group, Node, NodeExt, NodeKind, Opacity, Tree};
 
 use crate::holder::likes::types_like::TypesLike;
 use crate::holder::transform::{Transform, TransformError, TransformLogic};
 use crate::holder::utils;
-use crate::holder::utils::TranslateIntoResvgGeneric;
 use crate::plane::{Plane, PlaneError, SIZE};
 
 #[derive(Debug)]
@@ -15,6 +14,7 @@ use crate::plane::{Plane, PlaneError, SIZE};
 pub struct Object {
     data: TypesLike,
     id: String,
+    opacity: f64,
 }
 
 impl Object {
@@ -22,6 +22,7 @@ impl Object {
         Object {
             data,
             id: id.into(),
+            opacity: 1.0,
         }
     }
 
@@ -33,27 +34,51 @@ impl Object {
         &self.id
     }
 
+    /// Opacity of the whole object, it's applied after the content has been rendered and
+    /// therefore overlapping items don't shine through each other.
+    pub fn opacity(&self) -> f64 {
+        self.opacity
+    }
+
+    /// Appends the object as group with the id of the object to `node`, images are embedded as
+    /// png.
+    pub fn append_to_resvg_node(&self, node: &Node) -> Result<(), PlaneError> {
+        let node = node.append_kind(NodeKind::Group(Group {
+            id: self.id.clone(),
+            opacity: Opacity::new_clamped(self.opacity),
+            ..Group::default()
+        }));
+
+        match &self.data {
+            TypesLike::Svg(svg) => svg.append_to_resvg_node(&node),
+            TypesLike::Text(text) => text.svg_holder().append_to_resvg_node(&node),
+            TypesLike::Image(image_holder) | TypesLike::Composition(image_holder) => {
+                image_holder.append_to_resvg_node(&node)?
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Creates a resvg tree with the size `width` x `height` which contains the object.
+    pub fn to_resvg_tree(&self, width: SIZE, height: SIZE) -> Result<Tree, PlaneError> {
+        let tree = utils::new_resvg_tree(width, height)?;
+        self.append_to_resvg_node(&tree.root)?;
+
+        Ok(tree)
+    }
+
+    /// Writes the object as svg markup with the size `width` x `height`.
+    pub fn to_svg_string(&self, width: SIZE, height: SIZE) -> Result<String, PlaneError> {
+        let tree = self.to_resvg_tree(width, height)?;
+
+        Ok(utils::resvg_tree_to_svg_string(&tree))
+    }
+
     pub fn render(&self, width: SIZE, height: SIZE) -> Result<Plane, PlaneError> {
         match &self.data {
-            TypesLike::Svg(svg) => {
-                let size = Size::new(width as f64, height as f64)
-                    .ok_or(PlaneError::ValueGreaterZero("width or height"))?;
-
-                let tree = Tree {
-                    size,
-                    view_box: ViewBox {
-                        rect: size.to_rect(0.0, 0.0),
-                        aspect: AspectRatio::default(),
-                    },
-                    root: resvg::usvg::Node::new(resvg::usvg::NodeKind::Group(
-                        resvg::usvg::Group::default(),
-                    )),
-                };
-
-                for item in svg.items.values() {
-                    let node_kind = item.translate();
-                    tree.root.append_kind(node_kind);
-                }
+            TypesLike::Svg(_) | TypesLike::Text(_) => {
+                let tree = self.to_resvg_tree(width, height)?;
 
                 let mut pixmap = Pixmap::new(width, height).ok_or(PlaneError::TinySkiaError)?;
 
@@ -66,35 +91,8 @@ impl Object {
 
                 Ok(Plane::from_pixmap(pixmap))
             }
-            TypesLike::Image(image_holder) => {
-                let mut plane = Plane::new(width, height)?;
-
-                // TODO add offset (image.x.abs()) to enumerate
-                // image.x < 0
-                if true
-                /* TODO self.visibility */
-                {
-                    let x_iter = (image_holder.coordinates.x() as u32).min(width)
-                        ..((image_holder.coordinates + image_holder.size).x() as u32).min(width);
-
-                    for (x_image_cord, x_plane_cord) in x_iter.enumerate() {
-                        let x_image_cord = x_image_cord as u32;
-
-                        let y_iter = (image_holder.coordinates.y() as u32).min(height)
-                            ..((image_holder.coordinates + image_holder.size).y() as u32)
-                                .min(height);
-
-                        for (y_image_cord, y_plane_cord) in y_iter.enumerate() {
-                            let pixel = image_holder
-                                .data
-                                .pixel_unchecked(x_image_cord, y_image_cord as u32);
-
-                            plane.put_pixel_unchecked(x_plane_cord, y_plane_cord, *pixel);
-                        }
-                    }
-                }
-
-                Ok(plane)
+            TypesLike::Image(image_holder) | TypesLike::Composition(image_holder) => {
+                image_holder.render_with_opacity(width, height, self.opacity)
             }
         }
     }
@@ -128,8 +126,16 @@ impl Object {
 }
 
 impl TransformLogic for Object {
+    /// [`Transform::Opacity`] sets the opacity of the whole object, all other transformations
+    /// are applied to the content.
     fn transform(&mut self, transformation: &Transform) -> Result<(), TransformError> {
-        self.data.transform(transformation)
+        match transformation {
+            Transform::Opacity(opacity) => {
+                self.opacity = opacity.clamp(0.0, 1.0);
+                Ok(())
+            }
+            _ => self.data.transform(transformation),
+        }
     }
 
     fn transform_by_id(
@@ -137,6 +143,13 @@ impl TransformLogic for Object {
         id: impl Into<String>,
         transformation: &Transform,
     ) -> Result<(), TransformError> {
+        let id = id.into();
+
+        // the id of the object addresses the whole content, e.g. the image of an image layer
+        if id == self.id {
+            return self.transform(transformation);
+        }
+
         self.data.transform_by_id(id, transformation)
     }
 }
@@ -1,43 +1,113 @@
-use resvg::usvg::{NonZeroPositiveF64, NormalizedF64};
+use resvg::usvg::{NonZeroPositiveF64, NormalizedF64, StrokeMiterlimit};
 
 use crate::holder::likes::color_like::ColorLike;
 use crate::holder::utils::TranslateIntoResvgGeneric;
 
-#[derive(Debug, Clone)]
+/// Shape at the end of an open path.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub enum LineCap {
+    #[default]
+    Butt,
+    Round,
+    Square,
+}
+
+impl From<resvg::usvg::LineCap> for LineCap {
+    fn from(value: resvg::usvg::LineCap) -> Self {
+        match value {
+            resvg::usvg::LineCap::Butt => LineCap::Butt,
+            resvg::usvg::LineCap::Round => LineCap::Round,
+            resvg::usvg::LineCap::Square => LineCap::Square,
+        }
+    }
+}
+
+impl TranslateIntoResvgGeneric<resvg::usvg::LineCap> for LineCap {
+    fn translate(&self) -> resvg::usvg::LineCap {
+        match self {
+            LineCap::Butt => resvg::usvg::LineCap::Butt,
+            LineCap::Round => resvg::usvg::LineCap::Round,
+            LineCap::Square => resvg::usvg::LineCap::Square,
+        }
+    }
+}
+
+/// Shape at the corners of a path.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub enum LineJoin {
+    /// Sharp corner, falls back to [`LineJoin::Bevel`] if the `miterlimit` is exceeded
+    #[default]
+    Miter,
+    Round,
+    Bevel,
+}
+
+impl From<resvg::usvg::LineJoin> for LineJoin {
+    fn from(value: resvg::usvg::LineJoin) -> Self {
+        match value {
+            resvg::usvg::LineJoin::Miter => LineJoin::Miter,
+            resvg::usvg::LineJoin::Round => LineJoin::Round,
+            resvg::usvg::LineJoin::Bevel => LineJoin::Bevel,
+        }
+    }
+}
+
+impl TranslateIntoResvgGeneric<resvg::usvg::LineJoin> for LineJoin {
+    fn translate(&self) -> resvg::usvg::LineJoin {
+        match self {
+            LineJoin::Miter => resvg::usvg::LineJoin::Miter,
+            LineJoin::Round => resvg::usvg::LineJoin::Round,
+            LineJoin::Bevel => resvg::usvg::LineJoin::Bevel,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
 pub struct Stroke {
     pub paint: ColorLike,
     pub dasharray: Option<Vec<f64>>,
     pub dashoffset: f64,
-    // pub miterlimit: StrokeMiterlimit,
+    /// Ratio of the miter length to the stroke width, must be at least `1.0`
+    pub miterlimit: f64,
     pub opacity: f64,
+    /// A stroke with a width of `0.0` isn't drawn
     pub width: f64,
-    // pub linecap: LineCap,
-    // pub linejoin: LineJoin,
+    pub linecap: LineCap,
+    pub linejoin: LineJoin,
 }
 
 impl Default for Stroke {
     fn default() -> Self {
         let default = resvg::usvg::Stroke::default();
-        Self::from_resvg_stroke(default)
+        // the default paint is black and therefore always supported
+        Self::from_resvg_stroke(default).unwrap()
     }
 }
 
 impl Stroke {
-    pub fn from_resvg_stroke(stroke: resvg::usvg::Stroke) -> Stroke {
+    /// Converts a resvg stroke, returns `None` if the paint isn't supported.
+    pub fn from_resvg_stroke(stroke: resvg::usvg::Stroke) -> Option<Stroke> {
         // should fail when something changes in `resvg::usvg::Stroke` struct
         debug_assert_eq!(std::mem::size_of::<resvg::usvg::Stroke>(), 72);
 
-        let paint = ColorLike::from_resvg_paint(&stroke.paint);
+        let paint = ColorLike::from_resvg_paint(&stroke.paint)?;
 
-        Stroke {
+        Some(Stroke {
             paint,
             dasharray: stroke.dasharray,
             dashoffset: stroke.dashoffset as f64,
+            miterlimit: stroke.miterlimit.get(),
             opacity: stroke.opacity.get(),
             width: stroke.width.get(),
-        }
+            linecap: stroke.linecap.into(),
+            linejoin: stroke.linejoin.into(),
+        })
     }
 }
 
@@ -47,9 +117,83 @@ impl TranslateIntoResvgGeneric<resvg::usvg::Stroke> for Stroke {
             paint: self.paint.translate(),
             dasharray: self.dasharray.clone(),
             dashoffset: self.dashoffset as f32,
+            miterlimit: StrokeMiterlimit::new(self.miterlimit.max(1.0)),
             opacity: NormalizedF64::new(self.opacity.abs()).unwrap(),
             width: NonZeroPositiveF64::new(self.width.abs()).unwrap(),
-            ..resvg::usvg::Stroke::default()
+            linecap: self.linecap.translate(),
+            linejoin: self.linejoin.translate(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::holder::likes::PathLike;
+    use crate::holder::svg_holder::SvgHolder;
+    use crate::holder::svg_item::SvgItem;
+    use crate::pixel::Pixel;
+    use crate::point::Point;
+
+    fn stroke() -> Stroke {
+        Stroke {
+            paint: ColorLike::Color(Pixel::new(255, 0, 0, 255)),
+            dasharray: Some(vec![5.0, 2.5]),
+            dashoffset: 1.5,
+            miterlimit: 2.0,
+            opacity: 0.5,
+            width: 3.0,
+            linecap: LineCap::Round,
+            linejoin: LineJoin::Bevel,
         }
     }
+
+    #[test]
+    fn round_trip() {
+        let stroke = stroke();
+
+        assert_eq!(Stroke::from_resvg_stroke(stroke.translate()), Some(stroke));
+    }
+
+    #[test]
+    fn default_is_the_svg_default() {
+        let stroke = Stroke::default();
+
+        assert_eq!(stroke.miterlimit, 4.0);
+        assert_eq!(stroke.linecap, LineCap::Butt);
+        assert_eq!(stroke.linejoin, LineJoin::Miter);
+    }
+
+    #[test]
+    fn writes_svg() {
+        let line = |id: &str, stroke: Stroke| {
+            let mut item = SvgItem::new_with_id(
+                id,
+                vec![
+                    PathLike::Move(Point::ZERO),
+                    PathLike::Line(Point::new(10.0, 10.0)),
+                ],
+                None,
+            );
+            item.stroke = Some(stroke);
+            item
+        };
+
+        let holder = SvgHolder::new_with_items(vec![
+            line("line", stroke()),
+            line(
+                "no_width",
+                Stroke {
+                    width: 0.0,
+                    ..stroke()
+                },
+            ),
+        ]);
+        let svg = holder.to_svg_string(10, 10).unwrap();
+
+        assert!(svg.contains(r#"stroke-linecap="round""#));
+        assert!(svg.contains(r#"stroke-linejoin="bevel""#));
+        assert!(svg.contains(r#"stroke-miterlimit="2""#));
+        assert_eq!(svg.matches("stroke-width").count(), 1);
+    }
 }
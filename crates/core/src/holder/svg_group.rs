@@ -0,0 +1,146 @@
+use glam::DAffine2;
+use resvg::usvg::{Group, Node, NodeExt, NodeKind, Opacity};
+
+use crate::holder::svg_holder::SvgHolder;
+use crate::holder::transform::{Transform, TransformError, TransformLogic, TransformStack};
+use crate::holder::utils::{random_id, transform_bounding_box, TranslateIntoResvgGeneric};
+use crate::point::Point;
+
+/// A group of items and other groups, the transformations, the opacity and the visibility of the
+/// group are applied on top of the ones of its children.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct SvgGroup {
+    pub(crate) id: String,
+    pub(crate) children: SvgHolder,
+
+    pub(crate) opacity: f64,
+    pub(crate) visibility: bool,
+
+    pub(crate) transform: TransformStack,
+}
+
+impl SvgGroup {
+    pub fn new_with_id(id: impl Into<String>, children: SvgHolder) -> Self {
+        SvgGroup {
+            id: id.into(),
+            children,
+            opacity: 1.0,
+            visibility: true,
+            transform: TransformStack::default(),
+        }
+    }
+
+    pub fn new(children: SvgHolder) -> Self {
+        Self::new_with_id(random_id(), children)
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn children(&self) -> &SvgHolder {
+        &self.children
+    }
+
+    pub fn children_mut(&mut self) -> &mut SvgHolder {
+        &mut self.children
+    }
+
+    pub fn opacity(&self) -> f64 {
+        self.opacity
+    }
+
+    /// Sets the opacity of the whole group, the value is clamped between `0.0` and `1.0`.
+    pub fn set_opacity(&mut self, opacity: f64) {
+        self.opacity = opacity.clamp(0.0, 1.0);
+    }
+
+    pub fn visibility(&self) -> bool {
+        self.visibility
+    }
+
+    pub fn transform_stack(&self) -> &TransformStack {
+        &self.transform
+    }
+
+    pub fn transform_stack_mut(&mut self) -> &mut TransformStack {
+        &mut self.transform
+    }
+
+    /// Combines all transformations of the group into one matrix, the default origin is the center
+    /// of the bounding box of the children.
+    pub fn transform_matrix(&self) -> DAffine2 {
+        let center = match self.children.bounding_box() {
+            Some((min, max)) => (min + max) / 2.0,
+            None => Point::ZERO,
+        };
+        let center = self.transform.matrix.transform_point2(center.into());
+
+        self.transform.to_matrix(center.into())
+    }
+
+    /// Bounding box of all children with the transformations of the group applied, `None` if the
+    /// group has no children.
+    pub fn bounding_box(&self) -> Option<(Point, Point)> {
+        let bounding_box = self.children.bounding_box()?;
+
+        Some(transform_bounding_box(
+            &self.transform_matrix(),
+            bounding_box,
+        ))
+    }
+
+    /// Appends the group as `usvg::NodeKind::Group` with all its children to `node`.
+    ///
+    /// A hidden group is kept with an opacity of `0`, so the ids of the children aren't lost.
+    pub fn append_to_resvg_node(&self, node: &Node) {
+        let opacity = match self.visibility {
+            true => self.opacity,
+            false => 0.0,
+        };
+
+        let group = node.append_kind(NodeKind::Group(Group {
+            id: self.id.clone(),
+            transform: self.transform_matrix().translate(),
+            opacity: Opacity::new_clamped(opacity),
+            ..Group::default()
+        }));
+
+        self.children.append_to_resvg_node(&group);
+    }
+}
+
+impl TransformLogic for SvgGroup {
+    fn transform(&mut self, transformation: &Transform) -> Result<(), TransformError> {
+        match transformation {
+            Transform::Visibility(value) => self.visibility = *value,
+            Transform::Move(point) => self.transform.translation += *point,
+            Transform::Position(position) => {
+                if let Some((min, _)) = self.bounding_box() {
+                    self.transform.translation += *position - min;
+                }
+            }
+            Transform::Scale(factor) => self.transform.scale = *factor,
+            Transform::Rotate(angle) => self.transform.rotation = *angle,
+            Transform::Skew(angles) => self.transform.skew = *angles,
+            Transform::Origin(origin) => self.transform.origin = *origin,
+            Transform::Matrix(matrix) => self.transform.matrix = *matrix,
+            Transform::Opacity(opacity) => self.set_opacity(*opacity),
+            // a group has no paint and no path on its own
+            Transform::Color(_) | Transform::Stroke(_) | Transform::Trim(_, _) => {
+                self.children.transform(transformation)?
+            }
+            // the children would all get the same path
+            Transform::Path(_) => {
+                return Err(TransformError::NotImplemented(
+                    "Path",
+                    "SvgGroup".to_string(),
+                ))
+            }
+        }
+
+        Ok(())
+    }
+}
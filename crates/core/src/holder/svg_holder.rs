@@ -1,13 +1,111 @@
-use std::collections::HashMap;
+use std::path::Path;
 
+use resvg::usvg::{Node, NodeExt, NodeKind, Size, Tree, TreeParsing};
+use thiserror::Error;
+
+use crate::holder::svg_group::SvgGroup;
 use crate::holder::svg_item::SvgItem;
 use crate::holder::transform::{Transform, TransformError, TransformLogic};
+use crate::holder::utils::{self, TranslateIntoResvgGeneric};
+use crate::plane::{PlaneError, SIZE};
+use crate::point::Point;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum SvgHolderError {
+    #[error("No item with id `{0}`")]
+    NoItem(String),
+
+    #[error("Index `{index}` is out of bounds for `{len}` items")]
+    IndexOutOfBounds { index: usize, len: usize },
+
+    #[error("The new order must contain every item id exactly once")]
+    InvalidOrder,
+
+    #[error("An element with id `{0}` already exists")]
+    IdAlreadyExists(String),
+}
+
+#[derive(Error, Debug)]
+pub enum SvgImportError {
+    #[error("can't read the svg file")]
+    Io(#[from] std::io::Error),
+
+    #[error("can't parse the svg: {0}")]
+    Parse(#[from] resvg::usvg::Error),
+
+    #[error("width and height must be greater than zero")]
+    InvalidSize,
+}
+
+/// An element of a [`SvgHolder`], either a single item or a group of elements.
+// most elements are items, so boxing them isn't worth the extra allocation
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub enum SvgElement {
+    Item(SvgItem),
+    Group(SvgGroup),
+}
+
+impl SvgElement {
+    pub fn id(&self) -> &str {
+        match self {
+            SvgElement::Item(item) => item.id(),
+            SvgElement::Group(group) => group.id(),
+        }
+    }
 
+    /// Bounding box with all transformations applied, `None` for an empty group.
+    pub fn bounding_box(&self) -> Option<(Point, Point)> {
+        match self {
+            SvgElement::Item(item) => Some(item.bounding_box()),
+            SvgElement::Group(group) => group.bounding_box(),
+        }
+    }
+
+    pub fn append_to_resvg_node(&self, node: &Node) {
+        match self {
+            SvgElement::Item(item) => {
+                node.append_kind(item.translate());
+            }
+            SvgElement::Group(group) => group.append_to_resvg_node(node),
+        }
+    }
+}
+
+impl From<SvgItem> for SvgElement {
+    fn from(value: SvgItem) -> Self {
+        SvgElement::Item(value)
+    }
+}
+
+impl From<SvgGroup> for SvgElement {
+    fn from(value: SvgGroup) -> Self {
+        SvgElement::Group(value)
+    }
+}
+
+impl TransformLogic for SvgElement {
+    fn transform(&mut self, transformation: &Transform) -> Result<(), TransformError> {
+        match self {
+            SvgElement::Item(item) => item.transform(transformation),
+            SvgElement::Group(group) => group.transform(transformation),
+        }
+    }
+}
+
+/// Holds the [`SvgItem`]s and [`SvgGroup`]s of an svg object.
+///
+/// The elements are stored in paint order, the first element is painted first and therefore at
+/// the bottom, the last element is painted last and therefore on top of all other elements. The
+/// order functions like [`SvgHolder::bring_to_front`] only work on the elements of this holder,
+/// the children of a group are ordered with [`SvgGroup::children_mut`].
 #[derive(Debug, Default, Clone)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
 pub struct SvgHolder {
-    pub(crate) items: HashMap<String, SvgItem>,
+    pub(crate) elements: Vec<SvgElement>,
 }
 
 impl SvgHolder {
@@ -25,40 +123,884 @@ impl SvgHolder {
         svg_holder
     }
 
+    /// Imports all paths of a svg file, see [`SvgHolder::from_data`].
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, SvgImportError> {
+        let data = std::fs::read(path)?;
+        Self::from_data(&data)
+    }
+
+    /// Imports all paths of a svg file and fits them into `width` x `height`, see
+    /// [`SvgHolder::from_data_with_size`].
+    pub fn from_file_with_size(
+        path: impl AsRef<Path>,
+        width: f64,
+        height: f64,
+    ) -> Result<Self, SvgImportError> {
+        let data = std::fs::read(path)?;
+        Self::from_data_with_size(&data, width, height)
+    }
+
+    /// Imports all paths of a svg, the coordinates are in the size of the svg.
+    ///
+    /// Fills, strokes and gradients are converted and the ids of the elements are kept so they
+    /// can be targeted by [`TransformLogic::transform_by_id`]. The transforms of the elements and
+    /// the `viewBox` are baked into the paths. Texts and images are ignored.
+    pub fn from_data(data: &[u8]) -> Result<Self, SvgImportError> {
+        let tree = resvg::usvg::Tree::from_data(data, &resvg::usvg::Options::default())?;
+        Ok(Self::from_usvg_tree(&tree, tree.size))
+    }
+
+    /// Same as [`SvgHolder::from_data`] but the `viewBox` is fitted into `width` x `height` with
+    /// respect to the `preserveAspectRatio` of the svg.
+    pub fn from_data_with_size(
+        data: &[u8],
+        width: f64,
+        height: f64,
+    ) -> Result<Self, SvgImportError> {
+        let size = Size::new(width, height).ok_or(SvgImportError::InvalidSize)?;
+        let tree = resvg::usvg::Tree::from_data(data, &resvg::usvg::Options::default())?;
+        Ok(Self::from_usvg_tree(&tree, size))
+    }
+
+    fn from_usvg_tree(tree: &resvg::usvg::Tree, size: Size) -> Self {
+        let view_box_transform = resvg::usvg::utils::view_box_to_transform(
+            tree.view_box.rect,
+            tree.view_box.aspect,
+            size,
+        );
+
+        let mut svg_holder = Self::new();
+
+        // `descendants` walks the tree in document order, which is also the paint order
+        for node in tree.root.descendants() {
+            if let NodeKind::Path(ref path) = *node.borrow() {
+                let mut transform = view_box_transform;
+                transform.append(&node.abs_transform());
+
+                svg_holder.add_item(SvgItem::from_resvg_path(path, transform));
+            }
+        }
+
+        svg_holder
+    }
+
+    /// Appends all elements in paint order as children to `node`, groups are appended as
+    /// `usvg::NodeKind::Group`.
+    pub fn append_to_resvg_node(&self, node: &Node) {
+        for element in &self.elements {
+            element.append_to_resvg_node(node);
+        }
+    }
+
+    /// Creates a resvg tree with the size `width` x `height` which contains all items.
+    pub fn to_resvg_tree(&self, width: SIZE, height: SIZE) -> Result<Tree, PlaneError> {
+        let tree = utils::new_resvg_tree(width, height)?;
+        self.append_to_resvg_node(&tree.root);
+
+        Ok(tree)
+    }
+
+    /// Writes all items as svg markup with the size `width` x `height`.
+    ///
+    /// Every item is written as `<path>` and every group as `<g>` with its id, gradients are
+    /// written into `<defs>` with the id `{item id}_fill` or `{item id}_stroke`. Hidden items are
+    /// kept with `visibility="hidden"`.
+    pub fn to_svg_string(&self, width: SIZE, height: SIZE) -> Result<String, PlaneError> {
+        let tree = self.to_resvg_tree(width, height)?;
+
+        Ok(utils::resvg_tree_to_svg_string(&tree))
+    }
+
+    /// Adds the item on top of all other elements.
+    ///
+    /// If an element with the same id already exists, it is replaced and keeps its z-index.
     pub fn add_item(&mut self, item: SvgItem) -> String {
-        let id = item.id.clone();
-        self.items.insert(id.clone(), item);
+        self.add_element(item)
+    }
+
+    /// Adds the group on top of all other elements.
+    ///
+    /// If an element with the same id already exists, it is replaced and keeps its z-index.
+    pub fn add_group(&mut self, group: SvgGroup) -> String {
+        self.add_element(group)
+    }
+
+    fn add_element(&mut self, element: impl Into<SvgElement>) -> String {
+        let element = element.into();
+        let id = element.id().to_string();
+
+        match self.index_of(&id) {
+            Some(index) => self.elements[index] = element,
+            None => self.elements.push(element),
+        }
+
         id
     }
 
+    /// Moves the elements with the ids `keys` into a new group with the id `id`, the elements keep
+    /// their order and the group takes the z-index of the bottom most element.
+    ///
+    /// Fails if an element with the id `id` already exists, including one of the `keys`.
+    pub fn group<I: AsRef<str>>(
+        &mut self,
+        id: impl Into<String>,
+        keys: &[I],
+    ) -> Result<String, SvgHolderError> {
+        // check all keys before changing anything, so a failed group doesn't change the holder
+        for key in keys {
+            self.index_of_or_err(key.as_ref())?;
+        }
+
+        let id = id.into();
+        if self.get_element(&id).is_some() {
+            return Err(SvgHolderError::IdAlreadyExists(id));
+        }
+
+        let mut indices = keys
+            .iter()
+            .map(|key| self.index_of_or_err(key.as_ref()))
+            .collect::<Result<Vec<usize>, SvgHolderError>>()?;
+        indices.sort_unstable();
+        indices.dedup();
+
+        let mut children = SvgHolder::new();
+        for index in indices.iter().rev() {
+            children.elements.insert(0, self.elements.remove(*index));
+        }
+
+        let index = indices.first().copied().unwrap_or(self.elements.len());
+        self.elements
+            .insert(index, SvgGroup::new_with_id(id.clone(), children).into());
+
+        Ok(id)
+    }
+
+    /// Searches the item in this holder and in all groups.
     pub fn get_item(&self, key: impl Into<String>) -> Option<&SvgItem> {
-        self.items.get(&key.into())
+        match self.get_element(key)? {
+            SvgElement::Item(item) => Some(item),
+            SvgElement::Group(_) => None,
+        }
     }
 
+    /// Searches the item in this holder and in all groups.
     pub fn get_item_mut(&mut self, key: impl Into<String>) -> Option<&mut SvgItem> {
-        self.items.get_mut(&key.into())
+        match self.get_element_mut(key)? {
+            SvgElement::Item(item) => Some(item),
+            SvgElement::Group(_) => None,
+        }
+    }
+
+    /// Searches the group in this holder and in all groups.
+    pub fn get_group(&self, key: impl Into<String>) -> Option<&SvgGroup> {
+        match self.get_element(key)? {
+            SvgElement::Group(group) => Some(group),
+            SvgElement::Item(_) => None,
+        }
+    }
+
+    /// Searches the group in this holder and in all groups.
+    pub fn get_group_mut(&mut self, key: impl Into<String>) -> Option<&mut SvgGroup> {
+        match self.get_element_mut(key)? {
+            SvgElement::Group(group) => Some(group),
+            SvgElement::Item(_) => None,
+        }
+    }
+
+    /// Searches the element in this holder and in all groups.
+    pub fn get_element(&self, key: impl Into<String>) -> Option<&SvgElement> {
+        let key: String = key.into();
+        self.find_element(&key)
+    }
+
+    /// Searches the element in this holder and in all groups.
+    pub fn get_element_mut(&mut self, key: impl Into<String>) -> Option<&mut SvgElement> {
+        let key: String = key.into();
+        self.find_element_mut(&key)
+    }
+
+    fn find_element(&self, key: &str) -> Option<&SvgElement> {
+        self.elements.iter().find_map(|element| match element {
+            _ if element.id() == key => Some(element),
+            SvgElement::Group(group) => group.children.find_element(key),
+            SvgElement::Item(_) => None,
+        })
+    }
+
+    fn find_element_mut(&mut self, key: &str) -> Option<&mut SvgElement> {
+        for element in &mut self.elements {
+            if element.id() == key {
+                return Some(element);
+            }
+
+            if let SvgElement::Group(group) = element {
+                if let Some(element) = group.children.find_element_mut(key) {
+                    return Some(element);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Removes the element from this holder, the children of groups aren't searched.
+    pub fn remove_item(&mut self, key: impl Into<String>) -> Option<SvgElement> {
+        let index = self.index_of(key)?;
+        Some(self.elements.remove(index))
+    }
+
+    /// Returns all elements in paint order, from bottom to top.
+    pub fn elements(&self) -> &[SvgElement] {
+        &self.elements
+    }
+
+    /// Returns all items, also the ones in groups, in paint order, from bottom to top.
+    pub fn items(&self) -> Vec<&SvgItem> {
+        self.elements
+            .iter()
+            .flat_map(|element| match element {
+                SvgElement::Item(item) => vec![item],
+                SvgElement::Group(group) => group.children.items(),
+            })
+            .collect()
+    }
+
+    /// Returns the ids of all elements in paint order, from bottom to top.
+    pub fn ids(&self) -> Vec<&str> {
+        self.elements.iter().map(|element| element.id()).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Bounding box of all elements with their transformations applied, `None` if the holder has
+    /// no items.
+    pub fn bounding_box(&self) -> Option<(Point, Point)> {
+        self.elements
+            .iter()
+            .filter_map(|element| element.bounding_box())
+            .reduce(|(min_a, max_a), (min_b, max_b)| {
+                (
+                    Point::new(min_a.x().min(min_b.x()), min_a.y().min(min_b.y())),
+                    Point::new(max_a.x().max(max_b.x()), max_a.y().max(max_b.y())),
+                )
+            })
+    }
+
+    /// Returns the z-index of the element, `0` is the bottom most element.
+    pub fn index_of(&self, key: impl Into<String>) -> Option<usize> {
+        let key: String = key.into();
+        self.elements.iter().position(|element| element.id() == key)
+    }
+
+    fn index_of_or_err(&self, key: impl Into<String>) -> Result<usize, SvgHolderError> {
+        let key: String = key.into();
+        self.index_of(key.clone())
+            .ok_or(SvgHolderError::NoItem(key))
+    }
+
+    /// Moves the element to the given z-index, the other elements keep their relative order.
+    pub fn set_index(
+        &mut self,
+        key: impl Into<String>,
+        index: usize,
+    ) -> Result<(), SvgHolderError> {
+        let len = self.elements.len();
+        if index >= len {
+            return Err(SvgHolderError::IndexOutOfBounds { index, len });
+        }
+
+        let old_index = self.index_of_or_err(key)?;
+        let element = self.elements.remove(old_index);
+        self.elements.insert(index, element);
+
+        Ok(())
+    }
+
+    /// Moves the element on top of all other elements.
+    pub fn bring_to_front(&mut self, key: impl Into<String>) -> Result<(), SvgHolderError> {
+        let old_index = self.index_of_or_err(key)?;
+        let element = self.elements.remove(old_index);
+        self.elements.push(element);
+
+        Ok(())
+    }
+
+    /// Moves the element beneath all other elements.
+    pub fn send_to_back(&mut self, key: impl Into<String>) -> Result<(), SvgHolderError> {
+        let old_index = self.index_of_or_err(key)?;
+        let element = self.elements.remove(old_index);
+        self.elements.insert(0, element);
+
+        Ok(())
+    }
+
+    /// Inserts the item or group directly beneath the element with the id `key`.
+    ///
+    /// If an element with the same id as `element` already exists it gets replaced.
+    pub fn insert_before(
+        &mut self,
+        key: impl Into<String>,
+        element: impl Into<SvgElement>,
+    ) -> Result<String, SvgHolderError> {
+        self.insert_relative(key, element.into(), 0)
+    }
+
+    /// Inserts the item or group directly above the element with the id `key`.
+    ///
+    /// If an element with the same id as `element` already exists it gets replaced.
+    pub fn insert_after(
+        &mut self,
+        key: impl Into<String>,
+        element: impl Into<SvgElement>,
+    ) -> Result<String, SvgHolderError> {
+        self.insert_relative(key, element.into(), 1)
+    }
+
+    fn insert_relative(
+        &mut self,
+        key: impl Into<String>,
+        element: SvgElement,
+        offset: usize,
+    ) -> Result<String, SvgHolderError> {
+        // resolve the anchor before removing anything, so a failed insert doesn't change the holder
+        let mut anchor = self.index_of_or_err(key)?;
+
+        let id = element.id().to_string();
+        match self.index_of(id.clone()) {
+            // the element is its own anchor, it's replaced at the same place
+            Some(index) if index == anchor => {
+                self.elements[index] = element;
+                return Ok(id);
+            }
+            Some(index) => {
+                self.elements.remove(index);
+                if index < anchor {
+                    anchor -= 1;
+                }
+            }
+            None => {}
+        }
+
+        self.elements.insert(anchor + offset, element);
+
+        Ok(id)
+    }
+
+    /// Reorders all elements, `order` must contain the id of every element exactly once.
+    ///
+    /// The first id is painted first (bottom most), the last id is painted last (top most).
+    pub fn reorder<I: AsRef<str>>(&mut self, order: &[I]) -> Result<(), SvgHolderError> {
+        if order.len() != self.elements.len() {
+            return Err(SvgHolderError::InvalidOrder);
+        }
+
+        let indices = order
+            .iter()
+            .map(|id| self.index_of(id.as_ref()))
+            .collect::<Option<Vec<usize>>>()
+            .ok_or(SvgHolderError::InvalidOrder)?;
+
+        let mut sorted = indices.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+        if sorted.len() != indices.len() {
+            return Err(SvgHolderError::InvalidOrder);
+        }
+
+        let mut elements = self.elements.drain(..).map(Some).collect::<Vec<_>>();
+        self.elements = indices
+            .into_iter()
+            .filter_map(|index| elements[index].take())
+            .collect();
+
+        Ok(())
     }
 }
 
 impl TransformLogic for SvgHolder {
     fn transform(&mut self, transformation: &Transform) -> Result<(), TransformError> {
-        for item in &mut self.items.values_mut() {
-            item.transform(transformation)?;
+        for element in &mut self.elements {
+            element.transform(transformation)?;
         }
 
         Ok(())
     }
 
+    /// Transforms the item or group with the id `id`, the children of groups are searched too.
     fn transform_by_id(
         &mut self,
         id: impl Into<String>,
         transformation: &Transform,
     ) -> Result<(), TransformError> {
         let id: String = id.into();
-        let item = self
-            .get_item_mut(id.clone())
+        let element = self
+            .get_element_mut(id.clone())
             .ok_or(TransformError::NoItem(id))?;
 
-        item.transform(transformation)
+        element.transform(transformation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::holder::likes::{ColorLike, PathLike};
+    use crate::pixel::Pixel;
+    use crate::point::Point;
+
+    fn item(id: &str) -> SvgItem {
+        SvgItem::new_with_id(id, vec![PathLike::Move(Point::ZERO)], None)
+    }
+
+    fn holder() -> SvgHolder {
+        SvgHolder::new_with_items(vec![item("a"), item("b"), item("c")])
+    }
+
+    #[test]
+    fn keeps_insertion_order() {
+        let holder = holder();
+
+        assert_eq!(holder.ids(), vec!["a", "b", "c"]);
+        assert_eq!(holder.index_of("c"), Some(2));
+        assert_eq!(holder.index_of("d"), None);
+    }
+
+    #[test]
+    fn add_item_replaces_in_place() {
+        let mut holder = holder();
+        holder.add_item(item("a"));
+
+        assert_eq!(holder.ids(), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn bring_to_front_and_send_to_back() {
+        let mut holder = holder();
+
+        holder.bring_to_front("a").unwrap();
+        assert_eq!(holder.ids(), vec!["b", "c", "a"]);
+
+        holder.send_to_back("c").unwrap();
+        assert_eq!(holder.ids(), vec!["c", "b", "a"]);
+
+        assert_eq!(
+            holder.bring_to_front("d"),
+            Err(SvgHolderError::NoItem("d".to_string()))
+        );
+    }
+
+    #[test]
+    fn insert_before_and_after() {
+        let mut holder = holder();
+
+        holder.insert_before("b", item("d")).unwrap();
+        assert_eq!(holder.ids(), vec!["a", "d", "b", "c"]);
+
+        holder.insert_after("c", item("e")).unwrap();
+        assert_eq!(holder.ids(), vec!["a", "d", "b", "c", "e"]);
+
+        // moves an existing item
+        holder.insert_after("a", item("e")).unwrap();
+        assert_eq!(holder.ids(), vec!["a", "e", "d", "b", "c"]);
+
+        assert!(holder.insert_after("x", item("e")).is_err());
+        assert_eq!(holder.ids(), vec!["a", "e", "d", "b", "c"]);
+    }
+
+    #[test]
+    fn insert_relative_to_itself() {
+        let mut holder = holder();
+
+        holder.insert_before("b", item("b")).unwrap();
+        assert_eq!(holder.ids(), vec!["a", "b", "c"]);
+
+        holder.insert_after("b", item("b")).unwrap();
+        assert_eq!(holder.ids(), vec!["a", "b", "c"]);
+
+        // moves an item from beneath the anchor
+        holder.insert_after("c", item("a")).unwrap();
+        assert_eq!(holder.ids(), vec!["b", "c", "a"]);
+
+        holder.insert_before("c", item("b")).unwrap();
+        assert_eq!(holder.ids(), vec!["b", "c", "a"]);
+    }
+
+    #[test]
+    fn set_index() {
+        let mut holder = holder();
+
+        holder.set_index("c", 0).unwrap();
+        assert_eq!(holder.ids(), vec!["c", "a", "b"]);
+
+        assert_eq!(
+            holder.set_index("c", 3),
+            Err(SvgHolderError::IndexOutOfBounds { index: 3, len: 3 })
+        );
+    }
+
+    #[test]
+    fn reorder() {
+        let mut holder = holder();
+
+        holder.reorder(&["c", "a", "b"]).unwrap();
+        assert_eq!(holder.ids(), vec!["c", "a", "b"]);
+
+        assert_eq!(
+            holder.reorder(&["c", "a"]),
+            Err(SvgHolderError::InvalidOrder)
+        );
+        assert_eq!(
+            holder.reorder(&["c", "a", "a"]),
+            Err(SvgHolderError::InvalidOrder)
+        );
+        assert_eq!(holder.ids(), vec!["c", "a", "b"]);
+    }
+
+    mod import {
+        use super::*;
+
+        const SVG: &str = r##"<svg width="200" height="100" viewBox="0 0 100 50" xmlns="http://www.w3.org/2000/svg">
+            <defs>
+                <linearGradient id="gradient">
+                    <stop offset="0" stop-color="#ff0000"/>
+                    <stop offset="1" stop-color="#0000ff" stop-opacity="0.5"/>
+                </linearGradient>
+            </defs>
+            <rect id="rect" x="10" y="10" width="20" height="20" fill="#00ff00" fill-opacity="0.5"/>
+            <g transform="translate(50 0)">
+                <rect id="moved" x="0" y="0" width="10" height="10" fill="url(#gradient)" stroke="#000000" stroke-width="2"/>
+            </g>
+        </svg>"##;
+
+        #[test]
+        fn keeps_ids_and_order() {
+            let holder = SvgHolder::from_data(SVG.as_bytes()).unwrap();
+
+            assert_eq!(holder.ids(), vec!["rect", "moved"]);
+        }
+
+        #[test]
+        fn bakes_transforms() {
+            let holder = SvgHolder::from_data(SVG.as_bytes()).unwrap();
+
+            // the viewBox scales everything by two
+            let rect = holder.get_item("rect").unwrap();
+            assert_eq!(
+                PathLike::bounding_box(&rect.path),
+                (Point::new(20.0, 20.0), Point::new(60.0, 60.0))
+            );
+
+            let moved = holder.get_item("moved").unwrap();
+            assert_eq!(
+                PathLike::bounding_box(&moved.path),
+                (Point::new(100.0, 0.0), Point::new(120.0, 20.0))
+            );
+            assert_eq!(moved.stroke.as_ref().unwrap().width, 4.0);
+
+            let holder = SvgHolder::from_data_with_size(SVG.as_bytes(), 100.0, 50.0).unwrap();
+            let rect = holder.get_item("rect").unwrap();
+            assert_eq!(
+                PathLike::bounding_box(&rect.path),
+                (Point::new(10.0, 10.0), Point::new(30.0, 30.0))
+            );
+        }
+
+        #[test]
+        fn converts_colors() {
+            let holder = SvgHolder::from_data(SVG.as_bytes()).unwrap();
+
+            match &holder.get_item("rect").unwrap().fill_color {
+                Some(ColorLike::Color(color)) => assert_eq!(*color, Pixel::new(0, 255, 0, 128)),
+                other => panic!("expected a color, got {other:?}"),
+            }
+
+            match &holder.get_item("moved").unwrap().fill_color {
+                Some(ColorLike::LinearGradient(gradient)) => {
+                    let stops = gradient.base().stops();
+                    assert_eq!(stops.len(), 2);
+                    assert_eq!(stops[0].color(), Pixel::new(255, 0, 0, 255));
+                    assert_eq!(stops[1].color(), Pixel::new(0, 0, 255, 128));
+                }
+                other => panic!("expected a linear gradient, got {other:?}"),
+            }
+        }
+
+        #[test]
+        fn invalid_data() {
+            assert!(matches!(
+                SvgHolder::from_data(b"no svg"),
+                Err(SvgImportError::Parse(_))
+            ));
+            assert!(matches!(
+                SvgHolder::from_data_with_size(SVG.as_bytes(), 0.0, 10.0),
+                Err(SvgImportError::InvalidSize)
+            ));
+        }
+    }
+
+    mod export {
+        use super::*;
+        use crate::holder::gradient::base::BaseGradient;
+        use crate::holder::gradient::linear::LinearGradient;
+
+        fn rect(id: &str, from: Point, to: Point, fill_color: ColorLike) -> SvgItem {
+            let path = vec![
+                PathLike::Move(from),
+                PathLike::Line(Point::new(to.x(), from.y())),
+                PathLike::Line(to),
+                PathLike::Line(Point::new(from.x(), to.y())),
+                PathLike::Close,
+            ];
+
+            SvgItem::new_with_id(id, path, Some(fill_color))
+        }
+
+        fn holder() -> SvgHolder {
+            let gradient = LinearGradient::new(BaseGradient::new_from_colors(vec![
+                Pixel::new(255, 0, 0, 255),
+                Pixel::new(0, 0, 255, 255),
+            ]));
+
+            let mut hidden = rect(
+                "hidden",
+                Point::ZERO,
+                Point::new(10.0, 10.0),
+                ColorLike::Color(Pixel::new(0, 255, 0, 255)),
+            );
+            hidden.visibility = false;
+
+            SvgHolder::new_with_items(vec![
+                rect(
+                    "gradient",
+                    Point::new(10.0, 20.0),
+                    Point::new(50.0, 40.0),
+                    ColorLike::LinearGradient(gradient),
+                ),
+                hidden,
+            ])
+        }
+
+        #[test]
+        fn writes_svg() {
+            let svg = holder().to_svg_string(100, 50).unwrap();
+
+            assert!(svg.contains(r#"width="100""#));
+            assert!(svg.contains(r#"height="50""#));
+            assert!(svg.contains(r#"<linearGradient id="gradient_fill""#));
+            assert!(svg.contains(r##"fill="url(#gradient_fill)""##));
+            assert!(svg.contains(r#"visibility="hidden""#));
+        }
+
+        #[test]
+        fn round_trip() {
+            let holder = holder();
+            let svg = holder.to_svg_string(100, 50).unwrap();
+
+            let imported = SvgHolder::from_data(svg.as_bytes()).unwrap();
+
+            assert_eq!(imported.ids(), holder.ids());
+            for (item, imported_item) in holder.items().iter().zip(imported.items()) {
+                assert_eq!(
+                    PathLike::bounding_box(&item.path),
+                    PathLike::bounding_box(&imported_item.path)
+                );
+                assert_eq!(item.visibility, imported_item.visibility);
+            }
+
+            match &imported.get_item("gradient").unwrap().fill_color {
+                Some(ColorLike::LinearGradient(gradient)) => {
+                    let stops = gradient.base().stops();
+                    assert_eq!(stops[0].color(), Pixel::new(255, 0, 0, 255));
+                    assert_eq!(stops[1].color(), Pixel::new(0, 0, 255, 255));
+                }
+                other => panic!("expected a linear gradient, got {other:?}"),
+            }
+        }
+
+        #[test]
+        fn invalid_size() {
+            assert_eq!(
+                holder().to_svg_string(0, 50),
+                Err(PlaneError::ValueGreaterZero("width or height"))
+            );
+        }
+    }
+
+    mod group {
+        use super::*;
+        use crate::holder::likes::TypesLike;
+        use crate::holder::object::Object;
+
+        // red square from `from` with the size 10
+        fn square(id: &str, from: Point) -> SvgItem {
+            let path = vec![
+                PathLike::Move(from),
+                PathLike::Line(from + Point::new(10.0, 0.0)),
+                PathLike::Line(from + Point::new(10.0, 10.0)),
+                PathLike::Line(from + Point::new(0.0, 10.0)),
+                PathLike::Close,
+            ];
+
+            let mut item =
+                SvgItem::new_with_id(id, path, Some(ColorLike::Color(Pixel::new(255, 0, 0, 255))));
+            item.stroke = None;
+            item
+        }
+
+        fn holder() -> SvgHolder {
+            let mut holder = SvgHolder::new_with_items(vec![
+                square("a", Point::ZERO),
+                square("b", Point::new(10.0, 0.0)),
+                square("c", Point::new(20.0, 0.0)),
+                square("d", Point::new(30.0, 0.0)),
+            ]);
+            holder.group("group", &["d", "b"]).unwrap();
+
+            holder
+        }
+
+        fn render(holder: SvgHolder) -> crate::plane::Plane {
+            Object::new(TypesLike::Svg(holder)).render(50, 30).unwrap()
+        }
+
+        #[test]
+        fn groups_elements() {
+            let holder = holder();
+
+            assert_eq!(holder.ids(), vec!["a", "group", "c"]);
+            assert_eq!(
+                holder.get_group("group").unwrap().children().ids(),
+                vec!["b", "d"]
+            );
+            assert_eq!(
+                holder
+                    .items()
+                    .iter()
+                    .map(|item| item.id())
+                    .collect::<Vec<_>>(),
+                vec!["a", "b", "d", "c"]
+            );
+            assert_eq!(holder.get_item("d").unwrap().id(), "d");
+            assert!(holder.get_item("group").is_none());
+        }
+
+        #[test]
+        fn group_with_unknown_key() {
+            let mut holder = holder();
+
+            assert_eq!(
+                holder.group("other", &["a", "unknown"]),
+                Err(SvgHolderError::NoItem("unknown".to_string()))
+            );
+            assert_eq!(holder.ids(), vec!["a", "group", "c"]);
+        }
+
+        #[test]
+        fn group_with_existing_id() {
+            let mut holder = holder();
+
+            assert_eq!(
+                holder.group("c", &["a"]),
+                Err(SvgHolderError::IdAlreadyExists("c".to_string()))
+            );
+            assert_eq!(
+                holder.group("a", &["a", "c"]),
+                Err(SvgHolderError::IdAlreadyExists("a".to_string()))
+            );
+            assert_eq!(holder.ids(), vec!["a", "group", "c"]);
+        }
+
+        #[test]
+        fn bounding_box() {
+            let mut holder = holder();
+            assert_eq!(
+                holder.get_group("group").unwrap().bounding_box(),
+                Some((Point::new(10.0, 0.0), Point::new(40.0, 10.0)))
+            );
+
+            holder
+                .transform_by_id("group", &Transform::Move(Point::new(0.0, 5.0)))
+                .unwrap();
+            holder
+                .transform_by_id("group", &Transform::Scale(Point::new(2.0, 1.0)))
+                .unwrap();
+            assert_eq!(
+                holder.get_group("group").unwrap().bounding_box(),
+                Some((Point::new(-5.0, 5.0), Point::new(55.0, 15.0)))
+            );
+            assert_eq!(
+                holder.bounding_box(),
+                Some((Point::new(-5.0, 0.0), Point::new(55.0, 15.0)))
+            );
+        }
+
+        #[test]
+        fn transforms_nested_items() {
+            let mut holder = holder();
+            holder
+                .transform_by_id("group", &Transform::Move(Point::new(0.0, 10.0)))
+                .unwrap();
+            holder
+                .transform_by_id("d", &Transform::Move(Point::new(0.0, 10.0)))
+                .unwrap();
+
+            let plane = render(holder);
+
+            assert_eq!(plane.pixel_unchecked(5, 5), &Pixel::new(255, 0, 0, 255));
+            assert_eq!(plane.pixel_unchecked(15, 5), &Pixel::ZERO);
+            assert_eq!(plane.pixel_unchecked(15, 15), &Pixel::new(255, 0, 0, 255));
+            assert_eq!(plane.pixel_unchecked(35, 15), &Pixel::ZERO);
+            assert_eq!(plane.pixel_unchecked(35, 25), &Pixel::new(255, 0, 0, 255));
+        }
+
+        #[test]
+        fn opacity_and_visibility() {
+            let mut holder = holder();
+            holder.get_group_mut("group").unwrap().set_opacity(0.5);
+
+            let plane = render(holder.clone());
+            assert_eq!(plane.pixel_unchecked(5, 5)[3], 255);
+            assert_eq!(plane.pixel_unchecked(15, 5)[3], 128);
+
+            holder
+                .transform_by_id("group", &Transform::Visibility(false))
+                .unwrap();
+
+            let plane = render(holder);
+            assert_eq!(plane.pixel_unchecked(5, 5)[3], 255);
+            assert_eq!(plane.pixel_unchecked(15, 5), &Pixel::ZERO);
+        }
+
+        #[test]
+        fn color_is_applied_to_children() {
+            let mut holder = holder();
+            holder
+                .transform_by_id(
+                    "group",
+                    &Transform::Color(Some(ColorLike::Color(Pixel::new(0, 0, 255, 255)))),
+                )
+                .unwrap();
+
+            let plane = render(holder);
+            assert_eq!(plane.pixel_unchecked(5, 5), &Pixel::new(255, 0, 0, 255));
+            assert_eq!(plane.pixel_unchecked(15, 5), &Pixel::new(0, 0, 255, 255));
+        }
+
+        #[test]
+        fn writes_groups() {
+            let mut holder = holder();
+            holder.get_group_mut("group").unwrap().set_opacity(0.5);
+
+            let svg = holder.to_svg_string(50, 30).unwrap();
+
+            assert!(svg.contains(r#"<g id="group" opacity="0.5">"#));
+            assert!(svg.contains(r#"<path id="d""#));
+        }
     }
 }
@@ -1,29 +1,35 @@
 use std::rc::Rc;
 
+use glam::DAffine2;
+
 use crate::holder::likes::color_like::ColorLike;
 use crate::holder::likes::path_like::PathLike;
+use crate::holder::likes::path_measure::PathMeasure;
 use crate::holder::stroke::Stroke;
-use crate::holder::transform::{Transform, TransformError, TransformLogic};
-use crate::holder::utils::{random_id, TranslateIntoResvgGeneric};
+use crate::holder::transform::{Transform, TransformError, TransformLogic, TransformStack};
+use crate::holder::utils::{random_id, transform_from_resvg, TranslateIntoResvgGeneric};
 use crate::pixel::Pixel;
 use crate::point::Point;
 
-const BOUNDING_BOX_STEPS: i32 = 10;
-
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
 pub struct SvgItem {
     pub(crate) id: String,
+    #[cfg_attr(
+        any(feature = "serialize", feature = "deserialize"),
+        serde(with = "crate::holder::likes::path_like::path_string")
+    )]
     pub(crate) path: Vec<PathLike>,
 
     pub(crate) fill_color: Option<ColorLike>,
     pub(crate) stroke: Option<Stroke>,
 
     pub(crate) visibility: bool,
+    pub(crate) opacity: f64,
+    pub(crate) trim: (f64, f64),
 
-    last_rotation: f64,
-    last_scale: Point,
+    pub(crate) transform: TransformStack,
 }
 
 impl SvgItem {
@@ -38,8 +44,9 @@ impl SvgItem {
             fill_color,
             stroke: Some(Stroke::default()),
             visibility: true,
-            last_rotation: 0.0,
-            last_scale: Point::new_symmetric(1.0),
+            opacity: 1.0,
+            trim: (0.0, 1.0),
+            transform: TransformStack::default(),
         }
     }
 
@@ -47,74 +54,107 @@ impl SvgItem {
         Self::new_with_id(random_id(), path, fill_color)
     }
 
-    pub fn bounding_box(&self) -> (Point, Point) {
-        let mut smaller_corner = match self.path[0] {
-            PathLike::Move(p) => p,
-            _ => todo!(),
-        };
-        let mut bigger_corner = smaller_corner;
+    /// Converts a resvg path into a [`SvgItem`], `transform` is baked into the points of the path
+    /// and into the gradients. The id of the path is kept, if it has one.
+    ///
+    /// Patterns aren't supported, a fill or stroke with a pattern is removed.
+    pub fn from_resvg_path(path: &resvg::usvg::Path, transform: resvg::usvg::Transform) -> Self {
+        let mut data = (*path.data).clone();
+        data.transform(transform);
+        let segments = data.segments().map(PathLike::from_path_segment).collect();
+
+        let bounding_box = path
+            .data
+            .bbox()
+            .map(|b| {
+                (
+                    Point::new(b.left(), b.top()),
+                    Point::new(b.right(), b.bottom()),
+                )
+            })
+            .unwrap_or((Point::ZERO, Point::ONE));
+        let affine = transform_from_resvg(&transform);
+
+        let fill_color = path.fill.as_ref().and_then(|fill| {
+            let mut color_like = ColorLike::from_resvg_paint(&fill.paint)?;
+            color_like.multiply_opacity(fill.opacity.get());
+            color_like.bake_transform(affine, bounding_box);
+
+            Some(color_like)
+        });
 
-        let mut last_point = smaller_corner;
-        self.path.iter().for_each(|path| {
-            fn compare_and_set(value: &Point, smaller: &mut Point, bigger: &mut Point) {
-                if smaller.x() > value.x() {
-                    *smaller.x_mut() = value.x();
-                }
-                if smaller.y() > value.y() {
-                    *smaller.y_mut() = value.y();
-                }
+        let stroke = path.stroke.clone().and_then(|stroke| {
+            let mut stroke = Stroke::from_resvg_stroke(stroke)?;
+            stroke.paint.bake_transform(affine, bounding_box);
 
-                if bigger.x() < value.x() {
-                    *bigger.x_mut() = value.x();
-                }
-                if bigger.y() < value.y() {
-                    *bigger.y_mut() = value.y();
-                }
+            let (scale_x, scale_y) = transform.get_scale();
+            let factor = (scale_x + scale_y) / 2.0;
+            stroke.width *= factor;
+            stroke.dashoffset *= factor;
+            if let Some(dasharray) = stroke.dasharray.as_mut() {
+                dasharray.iter_mut().for_each(|dash| *dash *= factor);
             }
 
-            last_point = match path {
-                PathLike::Move(p) => {
-                    compare_and_set(p, &mut smaller_corner, &mut bigger_corner);
-                    *p
-                }
-                PathLike::Line(p) => {
-                    compare_and_set(p, &mut smaller_corner, &mut bigger_corner);
-                    *p
-                }
-                PathLike::CurveTo(p, c1, c2) => {
-                    use flo_curves::bezier::Curve;
-                    use flo_curves::*;
-
-                    fn point_to_coord2(p: &Point) -> Coord2 {
-                        Coord2(p.x(), p.y())
-                    }
-
-                    fn coord2_to_point(c: &Coord2) -> Point {
-                        Point::new(c.x(), c.y())
-                    }
-
-                    compare_and_set(p, &mut smaller_corner, &mut bigger_corner);
-
-                    let curve = Curve::from_points(
-                        point_to_coord2(&last_point),
-                        (point_to_coord2(c1), point_to_coord2(c2)),
-                        point_to_coord2(p),
-                    );
-
-                    for i in 0..BOUNDING_BOX_STEPS {
-                        let t = (i as f64) / (BOUNDING_BOX_STEPS as f64);
-                        let pos = curve.point_at_pos(t);
-                        let as_point = coord2_to_point(&pos);
-                        compare_and_set(&as_point, &mut smaller_corner, &mut bigger_corner);
-                    }
-
-                    *p
-                }
-                _ => last_point,
-            };
+            Some(stroke)
         });
 
-        (smaller_corner, bigger_corner)
+        let id = match path.id.is_empty() {
+            true => random_id(),
+            false => path.id.clone(),
+        };
+
+        let mut item = SvgItem::new_with_id(id, segments, fill_color);
+        item.stroke = stroke;
+        item.visibility = path.visibility == resvg::usvg::Visibility::Visible;
+
+        item
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn opacity(&self) -> f64 {
+        self.opacity
+    }
+
+    /// Start and end of the visible part of the path as percentages of its length.
+    pub fn trim(&self) -> (f64, f64) {
+        self.trim
+    }
+
+    /// Returns the visible part of the path, see [`Transform::Trim`].
+    pub fn trimmed_path(&self) -> Vec<PathLike> {
+        match self.trim {
+            (start, end) if start <= 0.0 && end >= 1.0 => self.path.clone(),
+            (start, end) => PathMeasure::new(&self.path).trim(start, end),
+        }
+    }
+
+    pub fn transform_stack(&self) -> &TransformStack {
+        &self.transform
+    }
+
+    pub fn transform_stack_mut(&mut self) -> &mut TransformStack {
+        &mut self.transform
+    }
+
+    /// Combines all transformations of the item into one matrix.
+    pub fn transform_matrix(&self) -> DAffine2 {
+        let center = PathLike::get_center(&self.path);
+        let center = self.transform.matrix.transform_point2(center.into());
+
+        self.transform.to_matrix(center.into())
+    }
+
+    /// Returns the path with all transformations of the item applied.
+    pub fn transformed_path(&self) -> Vec<PathLike> {
+        PathLike::transform_path(&self.path, &self.transform_matrix())
+    }
+
+    /// Bounding box of the path with all transformations applied.
+    pub fn bounding_box(&self) -> (Point, Point) {
+        PathLike::bounding_box(&self.transformed_path())
     }
 
     pub fn bounding_box_rect(&self) -> SvgItem {
@@ -157,23 +197,12 @@ impl TransformLogic for SvgItem {
     fn transform(&mut self, transformation: &Transform) -> Result<(), TransformError> {
         match &transformation {
             Transform::Visibility(value) => self.visibility = *value,
-            Transform::Move(point) => {
-                self.path = self
-                    .path
-                    .iter()
-                    .map(|p| match *p {
-                        PathLike::Move(og_p) => PathLike::Move(og_p + *point),
-                        PathLike::Line(og_p) => PathLike::Line(og_p + *point),
-                        PathLike::CurveTo(end, c1, c2) => {
-                            PathLike::CurveTo(end + *point, c1 + *point, c2 + *point)
-                        }
-                        PathLike::Close => PathLike::Close,
-                    })
-                    .collect();
-            }
+            Transform::Move(point) => self.transform.translation += *point,
             Transform::Position(position) => match self.path[0] {
-                PathLike::Move(point) => {
-                    let offset = *position - point;
+                // the first command is always absolute, even if it's relative
+                PathLike::Move(point) | PathLike::MoveRelative(point) => {
+                    let current = self.transform_matrix().transform_point2(point.into());
+                    let offset = *position - Point::from(current);
                     self.transform(&Transform::Move(offset))?
                 }
                 _ => panic!("First element needs to be a `PathLike::Move`"),
@@ -184,103 +213,14 @@ impl TransformLogic for SvgItem {
             Transform::Stroke(stroke) => {
                 self.stroke = stroke.clone();
             }
-            Transform::Scale(factor) => {
-                let center = PathLike::get_center(&self.path);
-
-                let factor_adjusted = *factor / self.last_scale;
-
-                self.path = self
-                    .path
-                    .iter()
-                    .map(|p| {
-                        let formatted = match *p {
-                            PathLike::Move(value) => {
-                                let v = (value - center) * factor_adjusted;
-                                let pos = center + v;
-                                PathLike::Move(pos)
-                            }
-                            PathLike::Line(value) => {
-                                let v = (value - center) * factor_adjusted;
-                                let pos = center + v;
-                                PathLike::Line(pos)
-                            }
-                            PathLike::CurveTo(end, c_s, c_e) => {
-                                let p_e = center + (end - center) * factor_adjusted;
-                                let p_c_s = center + (c_s - center) * factor_adjusted;
-                                let p_c_e = center + (c_e - center) * factor_adjusted;
-
-                                PathLike::CurveTo(p_e, p_c_s, p_c_e)
-                            }
-                            PathLike::Close => PathLike::Close,
-                        };
-
-                        formatted
-                    })
-                    .collect();
-
-                self.last_scale = *factor;
-            }
-            Transform::Rotate(angle) => {
-                let center = PathLike::get_center(&self.path);
-
-                let angle_diff = self.last_rotation - *angle;
-
-                self.path = self
-                    .path
-                    .iter()
-                    .map(|p| {
-                        // TODO make helper functions to remove duplicate code
-                        let formatted = match *p {
-                            PathLike::Move(value) => {
-                                let v = value - center;
-
-                                let x = angle_diff.cos() * v.x() - angle_diff.sin() * v.y();
-                                let y = angle_diff.sin() * v.x() + angle_diff.cos() * v.y();
-
-                                let pos = center + Point::new(x, y);
-                                PathLike::Move(pos)
-                            }
-                            PathLike::Line(value) => {
-                                let v = value - center;
-
-                                let x = angle_diff.cos() * v.x() - angle_diff.sin() * v.y();
-                                let y = angle_diff.sin() * v.x() + angle_diff.cos() * v.y();
-
-                                let pos = center + Point::new(x, y);
-                                PathLike::Line(pos)
-                            }
-                            PathLike::CurveTo(end, c_s, c_e) => {
-                                let v_e = end - center;
-                                let v_c_s = c_s - center;
-                                let v_c_e = c_e - center;
-
-                                let e = center
-                                    + Point::new(
-                                        angle_diff.cos() * v_e.x() - angle_diff.sin() * v_e.y(),
-                                        angle_diff.sin() * v_e.x() + angle_diff.cos() * v_e.y(),
-                                    );
-                                let cs = center
-                                    + Point::new(
-                                        angle_diff.cos() * v_c_s.x() - angle_diff.sin() * v_c_s.y(),
-                                        angle_diff.sin() * v_c_s.x() + angle_diff.cos() * v_c_s.y(),
-                                    );
-                                let ce = center
-                                    + Point::new(
-                                        angle_diff.cos() * v_c_e.x() - angle_diff.sin() * v_c_e.y(),
-                                        angle_diff.sin() * v_c_e.x() + angle_diff.cos() * v_c_e.y(),
-                                    );
-
-                                PathLike::CurveTo(e, cs, ce)
-                            }
-                            PathLike::Close => PathLike::Close,
-                        };
-
-                        formatted
-                    })
-                    .collect();
-
-                self.last_rotation = *angle;
-            }
+            Transform::Scale(factor) => self.transform.scale = *factor,
+            Transform::Rotate(angle) => self.transform.rotation = *angle,
+            Transform::Skew(angles) => self.transform.skew = *angles,
+            Transform::Origin(origin) => self.transform.origin = *origin,
+            Transform::Matrix(matrix) => self.transform.matrix = *matrix,
+            Transform::Opacity(opacity) => self.opacity = opacity.clamp(0.0, 1.0),
+            Transform::Trim(start, end) => self.trim = (start.clamp(0.0, 1.0), end.clamp(0.0, 1.0)),
+            Transform::Path(path) => self.path = path.clone(),
         };
 
         Ok(())
@@ -291,13 +231,26 @@ impl TranslateIntoResvgGeneric<resvg::usvg::NodeKind> for SvgItem {
     fn translate(&self) -> resvg::usvg::NodeKind {
         use resvg::usvg::*;
 
-        let mut path = PathData::new();
-        PathLike::extend_path_from_slice(&mut path, &self.path);
+        let path: PathData = self.trimmed_path().translate();
 
-        let fill = self
-            .fill_color
+        let fill = self.fill_color.as_ref().map(|color_like| {
+            let mut fill: resvg::usvg::Fill = color_like.translate();
+            fill.paint = paint_with_id(fill.paint, format!("{}_fill", self.id));
+            fill.opacity = Opacity::new_clamped(fill.opacity.get() * self.opacity);
+            fill
+        });
+
+        // resvg only supports strokes with a width greater than zero
+        let stroke = self
+            .stroke
             .as_ref()
-            .map(|color_like| color_like.translate());
+            .filter(|stroke| stroke.width != 0.0)
+            .map(|stroke| {
+                let mut stroke: resvg::usvg::Stroke = stroke.translate();
+                stroke.paint = paint_with_id(stroke.paint, format!("{}_stroke", self.id));
+                stroke.opacity = Opacity::new_clamped(stroke.opacity.get() * self.opacity);
+                stroke
+            });
 
         let visibility = match self.visibility {
             true => Visibility::Visible,
@@ -306,11 +259,201 @@ impl TranslateIntoResvgGeneric<resvg::usvg::NodeKind> for SvgItem {
 
         resvg::usvg::NodeKind::Path(resvg::usvg::Path {
             id: self.id.clone(),
+            transform: self.transform_matrix().translate(),
             visibility,
             fill,
-            stroke: self.stroke.clone().map(|s| s.translate()),
+            stroke,
             data: Rc::new(path),
             ..resvg::usvg::Path::default()
         })
     }
 }
+
+/// Gives a gradient paint an id, gradients are referenced by their id when the item is written as
+/// svg and therefore the id must be unique.
+fn paint_with_id(paint: resvg::usvg::Paint, id: String) -> resvg::usvg::Paint {
+    use resvg::usvg::Paint;
+
+    match paint {
+        Paint::LinearGradient(gradient) => {
+            let mut gradient = (*gradient).clone();
+            gradient.id = id;
+            Paint::LinearGradient(Rc::new(gradient))
+        }
+        Paint::RadialGradient(gradient) => {
+            let mut gradient = (*gradient).clone();
+            gradient.id = id;
+            Paint::RadialGradient(Rc::new(gradient))
+        }
+        paint => paint,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+
+    // square from (0, 0) to (10, 10) with the center (5, 5)
+    fn square() -> SvgItem {
+        SvgItem::new_with_id(
+            "square",
+            vec![
+                PathLike::Move(Point::ZERO),
+                PathLike::Line(Point::new(10.0, 0.0)),
+                PathLike::Line(Point::new(10.0, 10.0)),
+                PathLike::Line(Point::new(0.0, 10.0)),
+                PathLike::Close,
+            ],
+            None,
+        )
+    }
+
+    fn assert_bounding_box(item: &SvgItem, min: Point, max: Point) {
+        let bounding_box = item.bounding_box();
+
+        assert_abs_diff_eq!(bounding_box.0, min, epsilon = 0.0001);
+        assert_abs_diff_eq!(bounding_box.1, max, epsilon = 0.0001);
+    }
+
+    #[test]
+    fn keeps_the_path() {
+        let mut item = square();
+        let path = item.path.clone();
+
+        item.transform(&Transform::Move(Point::new(5.0, 5.0)))
+            .unwrap();
+        item.transform(&Transform::Rotate(1.0)).unwrap();
+
+        assert_eq!(item.path, path);
+    }
+
+    #[test]
+    fn move_and_position() {
+        let mut item = square();
+
+        item.transform(&Transform::Move(Point::new(5.0, 0.0)))
+            .unwrap();
+        item.transform(&Transform::Move(Point::new(0.0, 5.0)))
+            .unwrap();
+        assert_bounding_box(&item, Point::new(5.0, 5.0), Point::new(15.0, 15.0));
+
+        item.transform(&Transform::Position(Point::new(100.0, 50.0)))
+            .unwrap();
+        assert_bounding_box(&item, Point::new(100.0, 50.0), Point::new(110.0, 60.0));
+    }
+
+    #[test]
+    fn move_and_position_relative_path() {
+        let mut item = SvgItem::new(PathLike::parse("m 10 10 l 10 0 l 0 10 z").unwrap(), None);
+
+        item.transform(&Transform::Move(Point::new(100.0, 100.0)))
+            .unwrap();
+        assert_bounding_box(&item, Point::new(110.0, 110.0), Point::new(120.0, 120.0));
+
+        item.transform(&Transform::Position(Point::new(50.0, 0.0)))
+            .unwrap();
+        assert_bounding_box(&item, Point::new(50.0, 0.0), Point::new(60.0, 10.0));
+    }
+
+    #[test]
+    fn scale_and_rotate_are_absolute() {
+        let mut item = square();
+
+        item.transform(&Transform::Scale(Point::new(2.0, 1.0)))
+            .unwrap();
+        item.transform(&Transform::Scale(Point::new(2.0, 1.0)))
+            .unwrap();
+        assert_bounding_box(&item, Point::new(-5.0, 0.0), Point::new(15.0, 10.0));
+
+        for _ in 0..100 {
+            item.transform(&Transform::Rotate(std::f64::consts::FRAC_PI_2))
+                .unwrap();
+        }
+        assert_bounding_box(&item, Point::new(0.0, -5.0), Point::new(10.0, 15.0));
+    }
+
+    #[test]
+    fn rotates_around_the_origin() {
+        let mut item = square();
+
+        item.transform(&Transform::Origin(Some(Point::ZERO)))
+            .unwrap();
+        item.transform(&Transform::Rotate(std::f64::consts::FRAC_PI_2))
+            .unwrap();
+
+        // counterclockwise on the screen, the y axis points downwards
+        assert_bounding_box(&item, Point::new(0.0, -10.0), Point::new(10.0, 0.0));
+    }
+
+    #[test]
+    fn skew() {
+        let mut item = square();
+
+        item.transform(&Transform::Origin(Some(Point::ZERO)))
+            .unwrap();
+        item.transform(&Transform::Skew(Point::new(
+            std::f64::consts::FRAC_PI_4,
+            0.0,
+        )))
+        .unwrap();
+
+        assert_bounding_box(&item, Point::ZERO, Point::new(20.0, 10.0));
+    }
+
+    #[test]
+    fn matrix_is_applied_first() {
+        let mut item = square();
+
+        item.transform(&Transform::Matrix(DAffine2::from_translation(
+            (10.0, 0.0).into(),
+        )))
+        .unwrap();
+        // the center is moved by the matrix, so the scale happens around (15, 5)
+        item.transform(&Transform::Scale(Point::new_symmetric(2.0)))
+            .unwrap();
+
+        assert_bounding_box(&item, Point::new(5.0, -5.0), Point::new(25.0, 15.0));
+    }
+
+    #[test]
+    fn translates_into_the_node_transform() {
+        let mut item = square();
+        item.transform(&Transform::Move(Point::new(5.0, 10.0)))
+            .unwrap();
+
+        match item.translate() {
+            resvg::usvg::NodeKind::Path(path) => {
+                assert_eq!(
+                    path.transform,
+                    resvg::usvg::Transform::new_translate(5.0, 10.0)
+                );
+                assert_eq!(path.data.points()[0..2], [0.0, 0.0]);
+            }
+            _ => panic!("expected a path"),
+        }
+    }
+
+    #[test]
+    fn trim_is_applied_at_render_time() {
+        let mut item = square();
+        let path = item.path.clone();
+
+        item.transform(&Transform::Trim(0.0, 0.5)).unwrap();
+        assert_eq!(item.path, path);
+
+        match item.translate() {
+            resvg::usvg::NodeKind::Path(path) => {
+                assert_eq!(path.data.points(), [0.0, 0.0, 10.0, 0.0, 10.0, 10.0]);
+            }
+            _ => panic!("expected a path"),
+        }
+
+        item.transform(&Transform::Trim(0.5, 0.5)).unwrap();
+        match item.translate() {
+            resvg::usvg::NodeKind::Path(path) => assert!(path.data.is_empty()),
+            _ => panic!("expected a path"),
+        }
+    }
+}
@@ -0,0 +1,436 @@
+use std::path::Path;
+
+use thiserror::Error;
+use ttf_parser::{Face, FaceParsingError, GlyphId, OutlineBuilder};
+
+use crate::holder::likes::{ColorLike, PathLike};
+use crate::holder::svg_holder::SvgHolder;
+use crate::holder::svg_item::SvgItem;
+use crate::holder::transform::{Transform, TransformError, TransformLogic};
+use crate::holder::utils::random_id;
+use crate::pixel::Pixel;
+use crate::point::Point;
+
+#[derive(Error, Debug)]
+pub enum TextError {
+    #[error("can't read the font file")]
+    Io(#[from] std::io::Error),
+
+    #[error("can't parse the font: {0}")]
+    Parse(#[from] FaceParsingError),
+}
+
+/// A TTF or OTF font, the font is only needed to lay out the text of a [`TextHolder`].
+#[derive(Debug, Clone)]
+pub struct Font {
+    data: Vec<u8>,
+    index: u32,
+}
+
+impl Font {
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, TextError> {
+        Self::from_data(std::fs::read(path)?)
+    }
+
+    pub fn from_data(data: Vec<u8>) -> Result<Self, TextError> {
+        Self::from_data_with_index(data, 0)
+    }
+
+    /// Uses the font at `index` of a font collection (`.ttc` or `.otc`).
+    pub fn from_data_with_index(data: Vec<u8>, index: u32) -> Result<Self, TextError> {
+        Face::parse(&data, index)?;
+
+        Ok(Font { data, index })
+    }
+
+    fn face(&self) -> Face<'_> {
+        // the data has been checked in `from_data_with_index`
+        Face::parse(&self.data, self.index).expect("font data is valid")
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub enum TextAlign {
+    /// The lines start at the x coordinate of the position
+    #[default]
+    Left,
+    /// The lines are centered around the x coordinate of the position
+    Center,
+    /// The lines end at the x coordinate of the position
+    Right,
+}
+
+/// Which parts of the text get their own [`SvgItem`] and therefore their own id.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub enum TextSplit {
+    /// One item per glyph with the id `{id}_glyph_{index}`
+    Glyph,
+    /// One item per word with the id `{id}_word_{index}`
+    #[default]
+    Word,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct TextOptions {
+    pub font_size: f64,
+    pub align: TextAlign,
+    /// Multiplier for the line height of the font
+    pub line_spacing: f64,
+    pub split: TextSplit,
+    pub fill_color: Option<ColorLike>,
+}
+
+impl Default for TextOptions {
+    fn default() -> Self {
+        TextOptions {
+            font_size: 32.0,
+            align: TextAlign::default(),
+            line_spacing: 1.0,
+            split: TextSplit::default(),
+            fill_color: Some(ColorLike::Color(Pixel::BLACK)),
+        }
+    }
+}
+
+/// Holds a text as glyph outlines, the outlines are [`SvgItem`]s and are rendered like every
+/// other svg.
+///
+/// The `position` is the top of the first line, the x coordinate depends on [`TextAlign`].
+/// Whitespace doesn't create an item, the glyphs and words are counted from `0` without it.
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct TextHolder {
+    id: String,
+    text: String,
+    position: Point,
+    options: TextOptions,
+
+    svg: SvgHolder,
+}
+
+impl TextHolder {
+    pub fn new(
+        position: Point,
+        text: impl Into<String>,
+        font: &Font,
+        options: TextOptions,
+    ) -> Self {
+        Self::new_with_id(random_id(), position, text, font, options)
+    }
+
+    pub fn new_with_id(
+        id: impl Into<String>,
+        position: Point,
+        text: impl Into<String>,
+        font: &Font,
+        options: TextOptions,
+    ) -> Self {
+        let mut text_holder = TextHolder {
+            id: id.into(),
+            text: text.into(),
+            position,
+            options,
+            svg: SvgHolder::new(),
+        };
+        text_holder.layout(font);
+
+        text_holder
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn position(&self) -> Point {
+        self.position
+    }
+
+    pub fn options(&self) -> &TextOptions {
+        &self.options
+    }
+
+    /// Ids of all glyphs or words in reading order.
+    pub fn ids(&self) -> Vec<&str> {
+        self.svg.ids()
+    }
+
+    pub fn svg_holder(&self) -> &SvgHolder {
+        &self.svg
+    }
+
+    pub fn svg_holder_mut(&mut self) -> &mut SvgHolder {
+        &mut self.svg
+    }
+
+    fn layout(&mut self, font: &Font) {
+        let face = font.face();
+
+        let scale = self.options.font_size / face.units_per_em() as f64;
+        let ascender = face.ascender() as f64 * scale;
+        let line_height = (face.ascender() as f64 - face.descender() as f64
+            + face.line_gap() as f64)
+            * scale
+            * self.options.line_spacing;
+
+        let advance = |glyph: GlyphId| face.glyph_hor_advance(glyph).unwrap_or(0) as f64 * scale;
+        let glyph_of = |c: char| face.glyph_index(c).unwrap_or(GlyphId(0));
+
+        let mut items = Vec::new();
+        let mut glyph_index = 0;
+        let mut word_index = 0;
+        let mut word = Vec::new();
+
+        let mut finish_word = |word: &mut Vec<PathLike>, items: &mut Vec<SvgItem>| {
+            if !word.is_empty() {
+                items.push(self.item(format!("{}_word_{word_index}", self.id), word));
+                word_index += 1;
+            }
+        };
+
+        for (line_index, line) in self.text.lines().enumerate() {
+            let width: f64 = line
+                .chars()
+                .filter(|c| !c.is_control())
+                .map(|c| advance(glyph_of(c)))
+                .sum();
+
+            let mut x = match self.options.align {
+                TextAlign::Left => self.position.x(),
+                TextAlign::Center => self.position.x() - width / 2.0,
+                TextAlign::Right => self.position.x() - width,
+            };
+            let baseline = self.position.y() + ascender + line_index as f64 * line_height;
+
+            for c in line.chars().filter(|c| !c.is_control()) {
+                let glyph = glyph_of(c);
+
+                if c.is_whitespace() {
+                    finish_word(&mut word, &mut items);
+                } else {
+                    let mut builder = PathBuilder {
+                        origin: Point::new(x, baseline),
+                        scale,
+                        path: Vec::new(),
+                    };
+                    face.outline_glyph(glyph, &mut builder);
+
+                    match self.options.split {
+                        TextSplit::Glyph if !builder.path.is_empty() => items.push(self.item(
+                            format!("{}_glyph_{glyph_index}", self.id),
+                            &mut builder.path,
+                        )),
+                        TextSplit::Glyph => (),
+                        TextSplit::Word => word.append(&mut builder.path),
+                    }
+                    glyph_index += 1;
+                }
+
+                x += advance(glyph);
+            }
+
+            finish_word(&mut word, &mut items);
+        }
+
+        self.svg = SvgHolder::new_with_items(items);
+    }
+
+    fn item(&self, id: String, path: &mut Vec<PathLike>) -> SvgItem {
+        let mut item =
+            SvgItem::new_with_id(id, std::mem::take(path), self.options.fill_color.clone());
+        item.stroke = None;
+
+        item
+    }
+}
+
+/// Converts the outline of a glyph from font units into a path, the y axis of a font points
+/// upwards.
+struct PathBuilder {
+    origin: Point,
+    scale: f64,
+    path: Vec<PathLike>,
+}
+
+impl PathBuilder {
+    fn point(&self, x: f32, y: f32) -> Point {
+        self.origin + Point::new(x as f64, -y as f64) * self.scale
+    }
+}
+
+impl OutlineBuilder for PathBuilder {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.path.push(PathLike::Move(self.point(x, y)));
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.path.push(PathLike::Line(self.point(x, y)));
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        self.path
+            .push(PathLike::QuadTo(self.point(x, y), self.point(x1, y1)));
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        self.path.push(PathLike::CurveTo(
+            self.point(x, y),
+            self.point(x1, y1),
+            self.point(x2, y2),
+        ));
+    }
+
+    fn close(&mut self) {
+        self.path.push(PathLike::Close);
+    }
+}
+
+impl TransformLogic for TextHolder {
+    fn transform(&mut self, transformation: &Transform) -> Result<(), TransformError> {
+        self.svg.transform(transformation)
+    }
+
+    fn transform_by_id(
+        &mut self,
+        id: impl Into<String>,
+        transformation: &Transform,
+    ) -> Result<(), TransformError> {
+        self.svg.transform_by_id(id, transformation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn font() -> Font {
+        Font::from_file(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/data/fonts/DejaVuSansMono.ttf"
+        ))
+        .unwrap()
+    }
+
+    fn text(text: &str, options: TextOptions) -> TextHolder {
+        TextHolder::new_with_id("text", Point::new(100.0, 50.0), text, &font(), options)
+    }
+
+    fn bounding_box(text: &TextHolder, id: &str) -> (Point, Point) {
+        text.svg_holder().get_item(id).unwrap().bounding_box()
+    }
+
+    #[test]
+    fn invalid_font() {
+        assert!(matches!(
+            Font::from_data(vec![0, 1, 2, 3]),
+            Err(TextError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn ids_per_word() {
+        let text = text("Hello  rusvid\nworld", TextOptions::default());
+
+        assert_eq!(
+            text.ids(),
+            vec!["text_word_0", "text_word_1", "text_word_2"]
+        );
+    }
+
+    #[test]
+    fn ids_per_glyph() {
+        let options = TextOptions {
+            split: TextSplit::Glyph,
+            ..TextOptions::default()
+        };
+        let text = text("ab c", options);
+
+        assert_eq!(
+            text.ids(),
+            vec!["text_glyph_0", "text_glyph_1", "text_glyph_2"]
+        );
+    }
+
+    #[test]
+    fn empty_text() {
+        let text = text("", TextOptions::default());
+
+        assert!(text.ids().is_empty());
+    }
+
+    #[test]
+    fn align() {
+        let bounding_box_with = |align| {
+            let options = TextOptions {
+                align,
+                ..TextOptions::default()
+            };
+
+            bounding_box(&text("rusvid", options), "text_word_0")
+        };
+
+        let (min, max) = bounding_box_with(TextAlign::Left);
+        assert!(min.x() >= 100.0 && max.x() > 150.0);
+        assert!(min.y() > 50.0);
+
+        let (min, max) = bounding_box_with(TextAlign::Center);
+        assert!(min.x() < 100.0 && max.x() > 100.0);
+        assert!((100.0 - min.x() - (max.x() - 100.0)).abs() < 5.0);
+
+        let (min, max) = bounding_box_with(TextAlign::Right);
+        assert!(min.x() < 50.0 && max.x() <= 100.0);
+    }
+
+    #[test]
+    fn line_spacing() {
+        let second_line_with = |line_spacing| {
+            let options = TextOptions {
+                line_spacing,
+                ..TextOptions::default()
+            };
+
+            bounding_box(&text("a\na", options), "text_word_1").0.y()
+        };
+
+        let single = second_line_with(1.0);
+        let double = second_line_with(2.0);
+
+        let font = font();
+        let face = font.face();
+        let line_height = (face.ascender() - face.descender() + face.line_gap()) as f64 * 32.0
+            / face.units_per_em() as f64;
+        assert!((double - single - line_height).abs() < 0.0001);
+    }
+
+    #[test]
+    fn transforms_single_glyph() {
+        let options = TextOptions {
+            split: TextSplit::Glyph,
+            ..TextOptions::default()
+        };
+        let mut text = text("ab", options);
+        let before = (
+            bounding_box(&text, "text_glyph_0"),
+            bounding_box(&text, "text_glyph_1"),
+        );
+
+        text.transform_by_id("text_glyph_1", &Transform::Move(Point::new(0.0, 10.0)))
+            .unwrap();
+
+        assert_eq!(bounding_box(&text, "text_glyph_0"), before.0);
+        assert_eq!(
+            bounding_box(&text, "text_glyph_1").0,
+            before.1 .0 + Point::new(0.0, 10.0)
+        );
+    }
+}
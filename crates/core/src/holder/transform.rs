@@ -1,8 +1,10 @@
 use std::fmt::Debug;
 
+use glam::{DAffine2, DMat2, DVec2};
 use thiserror::Error;
 
 use crate::holder::likes::color_like::ColorLike;
+use crate::holder::likes::path_like::PathLike;
 use crate::holder::stroke::Stroke;
 use crate::point::Point;
 
@@ -54,8 +56,82 @@ pub enum Transform {
     /// Scale x and y by value
     Scale(Point),
 
-    /// Rotate by angle in radiant
+    /// Rotate by angle in radiant, a positive angle rotates counterclockwise
     Rotate(f64),
-    // TODO's
-    // Opacity(f32), // Should change the alpha chanel in the color
+
+    /// Skew along the x and y axis by angle in radiant
+    Skew(Point),
+
+    /// Set the point around which the object is scaled, skewed and rotated, `None` uses the
+    /// center of the path
+    Origin(Option<Point>),
+
+    /// Set an affine matrix which is applied before all other transformations
+    Matrix(DAffine2),
+
+    /// Set the opacity between `0.0` and `1.0`, it's multiplied with the alpha of the fill, the
+    /// stroke and the gradient stops
+    Opacity(f64),
+
+    /// Render only the part of the path between the start and the end, both are percentages
+    /// from `0.0` to `1.0` of the length of the path
+    Trim(f64, f64),
+
+    /// Replace the path of the object
+    Path(Vec<PathLike>),
+}
+
+/// The transformations of an item, they are applied at render time and don't change the path.
+///
+/// `Scale`, `Rotate` and `Skew` set absolute values, so setting them again doesn't accumulate.
+/// The transformations are applied in the order: `matrix`, scale, skew and rotation around the
+/// origin and last the translation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct TransformStack {
+    pub translation: Point,
+    pub scale: Point,
+    /// Angle in radiant, a positive angle rotates counterclockwise
+    pub rotation: f64,
+    /// Angles in radiant along the x and y axis
+    pub skew: Point,
+    /// Point around which is scaled, skewed and rotated, `None` uses the center of the path
+    pub origin: Option<Point>,
+    pub matrix: DAffine2,
+}
+
+impl Default for TransformStack {
+    fn default() -> Self {
+        TransformStack {
+            translation: Point::ZERO,
+            scale: Point::ONE,
+            rotation: 0.0,
+            skew: Point::ZERO,
+            origin: None,
+            matrix: DAffine2::IDENTITY,
+        }
+    }
+}
+
+impl TransformStack {
+    /// Combines all transformations into one matrix, `center` is used as origin if no origin is
+    /// set. `center` must already be transformed by [`TransformStack::matrix`].
+    pub fn to_matrix(&self, center: Point) -> DAffine2 {
+        let origin: DVec2 = self.origin.unwrap_or(center).into();
+
+        let skew = DAffine2::from_mat2(DMat2::from_cols(
+            DVec2::new(1.0, self.skew.y().tan()),
+            DVec2::new(self.skew.x().tan(), 1.0),
+        ));
+
+        // the y axis points downwards, therefore the angle is negated to rotate counterclockwise
+        DAffine2::from_translation(self.translation.into())
+            * DAffine2::from_translation(origin)
+            * DAffine2::from_angle(-self.rotation)
+            * skew
+            * DAffine2::from_scale(self.scale.into())
+            * DAffine2::from_translation(-origin)
+            * self.matrix
+    }
 }
@@ -1,5 +1,12 @@
+use glam::{DAffine2, DMat2, DVec2};
+use resvg::usvg::{
+    AspectRatio, Group, Node, NodeKind, Size, Tree, TreeWriting, ViewBox, XmlOptions,
+};
 use uuid::Uuid;
 
+use crate::plane::{PlaneError, SIZE};
+use crate::point::Point;
+
 pub fn random_id() -> String {
     Uuid::new_v4().to_string()
 }
@@ -7,3 +14,71 @@ pub fn random_id() -> String {
 pub trait TranslateIntoResvgGeneric<T> {
     fn translate(&self) -> T;
 }
+
+impl TranslateIntoResvgGeneric<resvg::usvg::Transform> for DAffine2 {
+    fn translate(&self) -> resvg::usvg::Transform {
+        let x_axis = self.matrix2.x_axis;
+        let y_axis = self.matrix2.y_axis;
+
+        resvg::usvg::Transform::new(
+            x_axis.x,
+            x_axis.y,
+            y_axis.x,
+            y_axis.y,
+            self.translation.x,
+            self.translation.y,
+        )
+    }
+}
+
+pub fn transform_from_resvg(transform: &resvg::usvg::Transform) -> DAffine2 {
+    DAffine2 {
+        matrix2: DMat2::from_cols(
+            DVec2::new(transform.a, transform.b),
+            DVec2::new(transform.c, transform.d),
+        ),
+        translation: DVec2::new(transform.e, transform.f),
+    }
+}
+
+/// Transforms the corners of the bounding box `(min, max)` and returns the bounding box of the
+/// transformed corners.
+pub fn transform_bounding_box(matrix: &DAffine2, bounding_box: (Point, Point)) -> (Point, Point) {
+    let (min, max) = bounding_box;
+    let corners = [
+        min,
+        Point::new(max.x(), min.y()),
+        max,
+        Point::new(min.x(), max.y()),
+    ]
+    .map(|corner| Point::from(matrix.transform_point2(corner.into())));
+
+    corners
+        .iter()
+        .fold((corners[0], corners[0]), |(min, max), corner| {
+            (
+                Point::new(min.x().min(corner.x()), min.y().min(corner.y())),
+                Point::new(max.x().max(corner.x()), max.y().max(corner.y())),
+            )
+        })
+}
+
+/// Creates an empty resvg tree with the size `width` x `height` and an empty group as root.
+pub fn new_resvg_tree(width: SIZE, height: SIZE) -> Result<Tree, PlaneError> {
+    let size = Size::new(width as f64, height as f64)
+        .ok_or(PlaneError::ValueGreaterZero("width or height"))?;
+
+    Ok(Tree {
+        size,
+        view_box: ViewBox {
+            rect: size.to_rect(0.0, 0.0),
+            aspect: AspectRatio::default(),
+        },
+        root: Node::new(NodeKind::Group(Group::default())),
+    })
+}
+
+/// Writes the tree as svg markup, gradients are written into `<defs>` and referenced by their id.
+pub fn resvg_tree_to_svg_string(tree: &Tree) -> String {
+    tree.to_string(&XmlOptions::default())
+}
@@ -13,6 +13,7 @@ pub mod plane;
 pub mod point;
 #[cfg(feature = "server")]
 pub mod server;
+pub mod working_plane;
 
 pub mod prelude {
     pub use crate::frame_image_format::FrameImageFormat;
@@ -96,6 +96,18 @@ impl Pixel {
         result
     }
 
+    /// Interpolates every channel linearly, `0.0` returns `self` and `1.0` returns `other`.
+    pub fn interpolate(&self, other: &Pixel, t: f64) -> Pixel {
+        let mut pixel = *self;
+
+        for i in 0..4 {
+            let value = (self[i] as f64) + ((other[i] as f64) - (self[i] as f64)) * t;
+            pixel[i] = value.round().clamp(0.0, 255.0) as u8;
+        }
+
+        pixel
+    }
+
     pub fn get_r(&self) -> u8 {
         self[0]
     }
@@ -276,6 +288,17 @@ mod tests {
         assert_eq!(p.to_raw(), [0, 0, 0, 255]);
     }
 
+    #[test]
+    fn interpolate() {
+        let a = Pixel::new(0, 100, 255, 255);
+        let b = Pixel::new(255, 100, 0, 0);
+
+        assert_eq!(a.interpolate(&b, 0.0), a);
+        assert_eq!(a.interpolate(&b, 0.5), Pixel::new(128, 100, 128, 128));
+        assert_eq!(a.interpolate(&b, 1.0), b);
+        assert_eq!(a.interpolate(&b, 2.0), Pixel::new(255, 100, 0, 0));
+    }
+
     mod getter_setter {
         macro_rules! generate_test_case {
             ($name:ident) => {
@@ -3,7 +3,7 @@ use std::io::{BufWriter, Read, Write};
 use std::path::{Path, PathBuf};
 
 use image::{DynamicImage, ImageFormat, RgbImage, RgbaImage};
-use resvg::tiny_skia::Pixmap;
+use resvg::tiny_skia::{ColorU8, Pixmap};
 use thiserror::Error;
 
 use crate::frame_image_format::FrameImageFormat;
@@ -205,12 +205,22 @@ impl Plane {
         Ok(plane)
     }
 
-    /// Consumes itself and tries to create an [`image::RgbImage`] or returns a [`PlaneError`].
+    /// Consumes itself and tries to create an [`image::RgbImage`] or returns a [`PlaneError`],
+    /// transparent pixels are mixed with black.
     pub fn as_rgb_image(self) -> PlaneResult<RgbImage> {
+        let over_black =
+            |value: u8, alpha: u8| ((value as u16 * alpha as u16) as f32 / 255.0).round() as u8;
+
         let buf = self
             .data
             .iter()
-            .flat_map(|v| [v[0], v[1], v[2]])
+            .flat_map(|v| {
+                [
+                    over_black(v[0], v[3]),
+                    over_black(v[1], v[3]),
+                    over_black(v[2], v[3]),
+                ]
+            })
             .collect::<Vec<u8>>();
 
         if self.width() * self.height() * 3 != buf.len() as SIZE {
@@ -255,29 +265,37 @@ impl Plane {
         Plane::from_data(width, height, data)
     }
 
-    /// Create a [`Plane`] from [`tiny_skia::Pixmap`]
+    /// Create a [`Plane`] from [`tiny_skia::Pixmap`], the premultiplied colors of the pixmap are
+    /// converted into straight colors.
     pub fn from_pixmap(pixmap: Pixmap) -> Self {
         let data = pixmap
             .pixels()
             .iter()
             .map(|x| {
-                let c = x.get();
-                let bytes = c.to_ne_bytes();
+                let c = x.demultiply();
 
-                Pixel::new_raw(bytes)
+                Pixel::new(c.red(), c.green(), c.blue(), c.alpha())
             })
             .collect::<Vec<Pixel>>();
 
         Plane::from_data_unchecked(pixmap.width(), pixmap.height(), data)
     }
 
-    /// Consumes itself and tries to create an [`tiny_skia::Pixmap`] or returns a [`PlaneError`].
+    /// Consumes itself and tries to create an [`tiny_skia::Pixmap`] or returns a [`PlaneError`],
+    /// the colors are premultiplied.
     pub fn as_pixmap(self) -> PlaneResult<Pixmap> {
+        self.to_pixmap()
+    }
+
+    /// Creates an [`tiny_skia::Pixmap`] with premultiplied colors from the plane, see
+    /// [`Plane::as_pixmap`].
+    pub fn to_pixmap(&self) -> PlaneResult<Pixmap> {
         let mut pixmap =
             Pixmap::new(self.width(), self.height()).ok_or(PlaneError::TinySkiaError)?;
 
-        let buf = self.as_data_flatten();
-        pixmap.data_mut()[..buf.len()].copy_from_slice(&buf[..]);
+        for (pixel, color) in pixmap.pixels_mut().iter_mut().zip(self.data.iter()) {
+            *pixel = ColorU8::from_rgba(color[0], color[1], color[2], color[3]).premultiply();
+        }
 
         Ok(pixmap)
     }
@@ -52,6 +52,18 @@ impl From<(f64, f64)> for Point {
     }
 }
 
+impl From<DVec2> for Point {
+    fn from(dvec: DVec2) -> Self {
+        Point::from_dvec(dvec)
+    }
+}
+
+impl From<Point> for DVec2 {
+    fn from(point: Point) -> Self {
+        point.0
+    }
+}
+
 impl AsRef<[f64; 2]> for Point {
     fn as_ref(&self) -> &[f64; 2] {
         self.0.as_ref()
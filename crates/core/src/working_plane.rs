@@ -0,0 +1,248 @@
+use crate::pixel::Pixel;
+use crate::plane::{Plane, SIZE};
+
+/// The color space in which colors are mixed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub enum ColorSpace {
+    /// Mixes the sRGB values directly, like most image editors and browsers
+    #[default]
+    Srgb,
+
+    /// Converts the sRGB values into linear light before mixing, this is physically correct and
+    /// avoids too dark transitions between bright colors
+    Linear,
+}
+
+impl ColorSpace {
+    /// Converts a sRGB channel from `0.0` to `1.0` into the color space.
+    pub fn from_srgb(&self, value: f32) -> f32 {
+        match self {
+            ColorSpace::Srgb => value,
+            ColorSpace::Linear if value <= 0.04045 => value / 12.92,
+            ColorSpace::Linear => ((value + 0.055) / 1.055).powf(2.4),
+        }
+    }
+
+    /// Converts a channel from `0.0` to `1.0` of the color space into sRGB.
+    pub fn to_srgb(&self, value: f32) -> f32 {
+        match self {
+            ColorSpace::Srgb => value,
+            ColorSpace::Linear if value <= 0.0031308 => value * 12.92,
+            ColorSpace::Linear => 1.055 * value.powf(1.0 / 2.4) - 0.055,
+        }
+    }
+}
+
+/// A pixel with premultiplied channels from `0.0` to `1.0`, it's used to mix colors without
+/// losing precision.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct WorkingPixel([f32; 4]);
+
+impl WorkingPixel {
+    pub const ZERO: WorkingPixel = WorkingPixel([0.0; 4]);
+
+    /// Creates the pixel from premultiplied channels.
+    pub const fn new_premultiplied(channels: [f32; 4]) -> Self {
+        WorkingPixel(channels)
+    }
+
+    /// Creates the pixel from a straight color without alpha and the alpha.
+    pub fn from_color(color: [f32; 3], alpha: f32) -> Self {
+        WorkingPixel([color[0] * alpha, color[1] * alpha, color[2] * alpha, alpha])
+    }
+
+    pub fn from_pixel(pixel: &Pixel, color_space: ColorSpace) -> Self {
+        let channel = |i: usize| color_space.from_srgb(pixel[i] as f32 / 255.0);
+
+        WorkingPixel::from_color(
+            [channel(0), channel(1), channel(2)],
+            pixel[3] as f32 / 255.0,
+        )
+    }
+
+    /// Converts the pixel into a straight sRGB pixel, this is the only place where the channels
+    /// are rounded.
+    pub fn to_pixel(&self, color_space: ColorSpace) -> Pixel {
+        let to_u8 = |value: f32| (value * 255.0).round().clamp(0.0, 255.0) as u8;
+
+        let color = self.color();
+        Pixel::new(
+            to_u8(color_space.to_srgb(color[0])),
+            to_u8(color_space.to_srgb(color[1])),
+            to_u8(color_space.to_srgb(color[2])),
+            to_u8(self.alpha()),
+        )
+    }
+
+    /// The premultiplied channels.
+    pub fn premultiplied(&self) -> [f32; 4] {
+        self.0
+    }
+
+    /// The straight color without alpha, a transparent pixel is black.
+    pub fn color(&self) -> [f32; 3] {
+        let alpha = self.alpha();
+        if alpha <= 0.0 {
+            return [0.0; 3];
+        }
+
+        [self.0[0] / alpha, self.0[1] / alpha, self.0[2] / alpha]
+    }
+
+    pub fn alpha(&self) -> f32 {
+        self.0[3]
+    }
+
+    /// Multiplies the alpha with `opacity` from `0.0` to `1.0`, the color doesn't change.
+    pub fn with_opacity(&self, opacity: f32) -> WorkingPixel {
+        WorkingPixel(self.0.map(|channel| channel * opacity))
+    }
+
+    /// Places `self` over `backdrop`.
+    pub fn over(&self, backdrop: &WorkingPixel) -> WorkingPixel {
+        let inverse = 1.0 - self.alpha();
+
+        WorkingPixel([
+            self.0[0] + backdrop.0[0] * inverse,
+            self.0[1] + backdrop.0[1] * inverse,
+            self.0[2] + backdrop.0[2] * inverse,
+            self.0[3] + backdrop.0[3] * inverse,
+        ])
+    }
+}
+
+/// A plane of [`WorkingPixel`]s in a [`ColorSpace`], planes are combined in this format and only
+/// converted back into a [`Plane`] at the end.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkingPlane {
+    width: SIZE,
+    height: SIZE,
+    color_space: ColorSpace,
+
+    data: Vec<WorkingPixel>,
+}
+
+impl WorkingPlane {
+    /// Creates a fully transparent plane.
+    pub fn new(width: SIZE, height: SIZE, color_space: ColorSpace) -> Self {
+        WorkingPlane {
+            width,
+            height,
+            color_space,
+            data: vec![WorkingPixel::ZERO; (width * height) as usize],
+        }
+    }
+
+    pub fn from_plane(plane: &Plane, color_space: ColorSpace) -> Self {
+        WorkingPlane {
+            width: plane.width(),
+            height: plane.height(),
+            color_space,
+            data: plane
+                .as_data()
+                .iter()
+                .map(|pixel| WorkingPixel::from_pixel(pixel, color_space))
+                .collect(),
+        }
+    }
+
+    pub fn to_plane(&self) -> Plane {
+        let data = self
+            .data
+            .iter()
+            .map(|pixel| pixel.to_pixel(self.color_space))
+            .collect();
+
+        Plane::from_data_unchecked(self.width, self.height, data)
+    }
+
+    pub fn width(&self) -> SIZE {
+        self.width
+    }
+
+    pub fn height(&self) -> SIZE {
+        self.height
+    }
+
+    pub fn color_space(&self) -> ColorSpace {
+        self.color_space
+    }
+
+    pub fn as_data(&self) -> &Vec<WorkingPixel> {
+        &self.data
+    }
+
+    pub fn as_data_mut(&mut self) -> &mut Vec<WorkingPixel> {
+        &mut self.data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+
+    #[test]
+    fn converts_without_loss() {
+        for color_space in [ColorSpace::Srgb, ColorSpace::Linear] {
+            for value in 0..=255 {
+                for alpha in [1, 64, 128, 255] {
+                    let pixel = Pixel::new(value, 255 - value, value / 2, alpha);
+                    let working = WorkingPixel::from_pixel(&pixel, color_space);
+
+                    assert_eq!(working.to_pixel(color_space), pixel);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn linear_light() {
+        let gray = WorkingPixel::from_pixel(&Pixel::new(188, 188, 188, 255), ColorSpace::Linear);
+        assert_abs_diff_eq!(gray.color()[0], 0.5, epsilon = 0.005);
+
+        assert_eq!(ColorSpace::Linear.from_srgb(0.0), 0.0);
+        assert_abs_diff_eq!(ColorSpace::Linear.from_srgb(1.0), 1.0);
+        assert_abs_diff_eq!(ColorSpace::Linear.to_srgb(1.0), 1.0, epsilon = 0.000001);
+    }
+
+    #[test]
+    fn over() {
+        let half_white = WorkingPixel::from_color([1.0; 3], 0.5);
+        let black = WorkingPixel::from_color([0.0; 3], 1.0);
+
+        let mixed = half_white.over(&black);
+        assert_eq!(mixed.premultiplied(), [0.5, 0.5, 0.5, 1.0]);
+
+        // the color of a transparent backdrop doesn't bleed into the result
+        let mixed = half_white.over(&WorkingPixel::ZERO);
+        assert_eq!(mixed.color(), [1.0; 3]);
+        assert_eq!(mixed.alpha(), 0.5);
+    }
+
+    #[test]
+    fn with_opacity() {
+        let pixel = WorkingPixel::from_color([1.0, 0.5, 0.0], 0.8);
+        let faded = pixel.with_opacity(0.5);
+
+        assert_abs_diff_eq!(faded.alpha(), 0.4);
+        assert_eq!(faded.color(), pixel.color());
+    }
+
+    #[test]
+    fn plane_round_trip() {
+        let plane = Plane::from_data_unchecked(
+            2,
+            1,
+            vec![Pixel::new(255, 0, 0, 128), Pixel::new(10, 20, 30, 255)],
+        );
+
+        let working = WorkingPlane::from_plane(&plane, ColorSpace::Linear);
+        assert_eq!(working.width(), 2);
+        assert_eq!(working.as_data().len(), 2);
+        assert_eq!(working.to_plane().as_data(), plane.as_data());
+    }
+}
@@ -227,3 +227,51 @@ fn simple_transform() {
     )
     .unwrap();
 }
+
+#[test]
+fn z_order() {
+    fn square(id: &str, offset: f64, color: Pixel) -> SvgItem {
+        let mut item = SvgItem::new_with_id(
+            id,
+            vec![
+                PathLike::Move(Point::new(offset, offset)),
+                PathLike::Line(Point::new(offset + 50.0, offset)),
+                PathLike::Line(Point::new(offset + 50.0, offset + 50.0)),
+                PathLike::Line(Point::new(offset, offset + 50.0)),
+                PathLike::Close,
+            ],
+            Some(ColorLike::Color(color)),
+        );
+        item.transform(&Transform::Stroke(None)).unwrap();
+        item
+    }
+
+    let red = Pixel::new(255, 0, 0, 255);
+    let green = Pixel::new(0, 255, 0, 255);
+    let blue = Pixel::new(0, 0, 255, 255);
+
+    let svg = SvgHolder::new_with_items(vec![
+        square("red", 0.0, red),
+        square("green", 10.0, green),
+        square("blue", 20.0, blue),
+    ]);
+    let mut object = Object::new(TypesLike::Svg(svg));
+
+    // render multiple times to make sure the paint order is stable
+    for _ in 0..10 {
+        let plane = object.render(100, 100).unwrap();
+        assert_eq!(plane.pixel_unchecked(30, 30), &blue);
+    }
+
+    if let TypesLike::Svg(svg) = object.data_mut() {
+        svg.send_to_back("blue").unwrap();
+    }
+    let plane = object.render(100, 100).unwrap();
+    assert_eq!(plane.pixel_unchecked(30, 30), &green);
+
+    if let TypesLike::Svg(svg) = object.data_mut() {
+        svg.bring_to_front("red").unwrap();
+    }
+    let plane = object.render(100, 100).unwrap();
+    assert_eq!(plane.pixel_unchecked(30, 30), &red);
+}
@@ -123,6 +123,12 @@ pub fn transform_visibility(value: bool) {
     object.transform(&Transform::Visibility(value)).unwrap();
 }
 
+#[wasm_bindgen]
+pub fn transform_opacity(value: f64) {
+    let mut object = OBJECT.lock().unwrap();
+    object.transform(&Transform::Opacity(value)).unwrap();
+}
+
 #[wasm_bindgen]
 pub fn transform_rotate(value: f64) {
     let mut object = OBJECT.lock().unwrap();
@@ -144,19 +150,25 @@ pub fn calculate_bounding_box(id: String) -> Option<js_sys::Int32Array> {
     let object = OBJECT.lock().unwrap();
     let data = object.data();
 
-    match data {
-        TypesLike::Svg(svg_holder) => svg_holder.get_item(id).map(|item| {
-            let bounding = item.bounding_box();
+    let bounding = match data {
+        TypesLike::Svg(svg_holder) => svg_holder.get_item(id).map(|item| item.bounding_box()),
+        TypesLike::Image(image_holder) | TypesLike::Composition(image_holder) => {
+            Some(image_holder.bounding_box())
+        }
+        TypesLike::Text(text_holder) => text_holder
+            .svg_holder()
+            .get_item(id)
+            .map(|item| item.bounding_box()),
+    };
 
-            let x1 = bounding.0.x() as i32;
-            let y1 = bounding.0.y() as i32;
-            let x2 = bounding.1.x() as i32;
-            let y2 = bounding.1.y() as i32;
+    bounding.map(|bounding| {
+        let x1 = bounding.0.x() as i32;
+        let y1 = bounding.0.y() as i32;
+        let x2 = bounding.1.x() as i32;
+        let y2 = bounding.1.y() as i32;
 
-            js_sys::Int32Array::from(&vec![x1, y1, x2, y2][..])
-        }),
-        TypesLike::Image(_) => todo!(),
-    }
+        js_sys::Int32Array::from(&vec![x1, y1, x2, y2][..])
+    })
 }
 
 #[wasm_bindgen]
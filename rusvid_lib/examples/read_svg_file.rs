@@ -1,4 +1,3 @@
-/*
 use std::env;
 use std::path::PathBuf;
 
@@ -17,7 +16,7 @@ fn main() {
         .unwrap()
         .as_path()
         .join("./rusvid_lib/examples/assets/rect_gradient.svg");
-    let layer = Layer::from_file(composition.resolution(), &path).unwrap();
+    let layer = Layer::from_file(composition.resolution(), path).unwrap();
     composition.add_layer(layer);
 
     let out_path = PathBuf::from("read_svg_file.mp4");
@@ -25,8 +24,3 @@ fn main() {
     let mut renderer = EmbeddedRenderer::new(out_path);
     renderer.render(composition).unwrap()
 }
- */
-
-fn main() {
-    todo!()
-}
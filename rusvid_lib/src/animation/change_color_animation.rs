@@ -2,6 +2,7 @@ use rusvid_core::holder::likes::ColorLike;
 use rusvid_core::prelude::Pixel;
 
 use super::{Animation, EaseType, FunctionType, Range};
+use crate::types::FPS;
 
 #[derive(Debug)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
@@ -66,4 +67,8 @@ impl Animation for ChangeColorAnimation {
     fn end_frame(&self) -> usize {
         self.frame_range.end_bound()
     }
+
+    fn resolve_time(&mut self, framerate: FPS) {
+        self.frame_range.resolve(framerate);
+    }
 }
@@ -0,0 +1,69 @@
+use rusvid_core::holder::likes::ColorLike;
+
+use super::{Animation, EaseType, FunctionType, Range};
+use crate::types::FPS;
+
+/// Interpolates between two colors or gradients, the stop colors, the stop offsets and the points
+/// of the gradients are interpolated. A solid color is promoted to a gradient with one stop, see
+/// [`ColorLike::interpolate`].
+#[derive(Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct ChangeGradientAnimation {
+    object_id: String,
+
+    curve: FunctionType,
+    ease: EaseType,
+
+    frame_range: Range,
+
+    start_color: ColorLike,
+    end_color: ColorLike,
+}
+
+impl ChangeGradientAnimation {
+    pub fn new<I: Into<String> + Clone>(
+        id: &I,
+        frames: impl Into<Range>,
+        colors: (ColorLike, ColorLike),
+        curve: FunctionType,
+        ease: EaseType,
+    ) -> Self {
+        Self {
+            curve,
+            ease,
+            object_id: id.clone().into(),
+            frame_range: frames.into(),
+            start_color: colors.0,
+            end_color: colors.1,
+        }
+    }
+}
+
+impl Animation for ChangeGradientAnimation {
+    fn object_id(&self) -> &str {
+        &self.object_id
+    }
+
+    type OUTPUT = ColorLike;
+    fn get_value(&self, frame: usize) -> Self::OUTPUT {
+        let percentage = self.frame_range.percentage(frame);
+
+        self.start_color.interpolate(
+            &self.end_color,
+            self.curve.delta(self.ease, percentage) as f64,
+        )
+    }
+
+    fn start_frame(&self) -> usize {
+        self.frame_range.start()
+    }
+
+    fn end_frame(&self) -> usize {
+        self.frame_range.end_bound()
+    }
+
+    fn resolve_time(&mut self, framerate: FPS) {
+        self.frame_range.resolve(framerate);
+    }
+}
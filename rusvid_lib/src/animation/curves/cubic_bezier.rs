@@ -0,0 +1,164 @@
+use crate::animation::Function;
+
+const NEWTON_ITERATIONS: usize = 8;
+const BISECTION_ITERATIONS: usize = 32;
+const EPSILON: f64 = 1e-7;
+
+/// Easing curve like the css function `cubic-bezier(x1, y1, x2, y2)`, the curve starts at
+/// `(0, 0)` and ends at `(1, 1)`.
+///
+/// The curve is used as given for [`crate::animation::EaseType::In`],
+/// [`crate::animation::EaseType::Out`] mirrors it and [`crate::animation::EaseType::InOut`] uses
+/// the curve for the first half and the mirrored curve for the second half.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(
+    feature = "deserialize",
+    derive(serde::Deserialize),
+    serde(from = "UncheckedCubicBezier")
+)]
+pub struct CubicBezier {
+    x1: f64,
+    y1: f64,
+    x2: f64,
+    y2: f64,
+}
+
+/// Deserialized values of a [`CubicBezier`], they are clamped by [`CubicBezier::new`].
+#[cfg(feature = "deserialize")]
+#[derive(serde::Deserialize)]
+struct UncheckedCubicBezier {
+    x1: f64,
+    y1: f64,
+    x2: f64,
+    y2: f64,
+}
+
+#[cfg(feature = "deserialize")]
+impl From<UncheckedCubicBezier> for CubicBezier {
+    fn from(value: UncheckedCubicBezier) -> Self {
+        CubicBezier::new(value.x1, value.y1, value.x2, value.y2)
+    }
+}
+
+impl CubicBezier {
+    /// Creates the curve, like in css `x1` and `x2` are clamped between `0.0` and `1.0`.
+    pub fn new(x1: f64, y1: f64, x2: f64, y2: f64) -> Self {
+        CubicBezier {
+            x1: x1.clamp(0.0, 1.0),
+            y1,
+            x2: x2.clamp(0.0, 1.0),
+            y2,
+        }
+    }
+
+    /// css `ease`
+    pub const EASE: CubicBezier = CubicBezier {
+        x1: 0.25,
+        y1: 0.1,
+        x2: 0.25,
+        y2: 1.0,
+    };
+
+    /// css `ease-in`
+    pub const EASE_IN: CubicBezier = CubicBezier {
+        x1: 0.42,
+        y1: 0.0,
+        x2: 1.0,
+        y2: 1.0,
+    };
+
+    /// css `ease-out`
+    pub const EASE_OUT: CubicBezier = CubicBezier {
+        x1: 0.0,
+        y1: 0.0,
+        x2: 0.58,
+        y2: 1.0,
+    };
+
+    /// css `ease-in-out`
+    pub const EASE_IN_OUT: CubicBezier = CubicBezier {
+        x1: 0.42,
+        y1: 0.0,
+        x2: 0.58,
+        y2: 1.0,
+    };
+
+    /// One coordinate of the curve at `t`, `p1` and `p2` are the coordinates of the control
+    /// points.
+    fn sample(p1: f64, p2: f64, t: f64) -> f64 {
+        let c = 3.0 * p1;
+        let b = 3.0 * (p2 - p1) - c;
+        let a = 1.0 - c - b;
+
+        ((a * t + b) * t + c) * t
+    }
+
+    fn sample_derivative(p1: f64, p2: f64, t: f64) -> f64 {
+        let c = 3.0 * p1;
+        let b = 3.0 * (p2 - p1) - c;
+        let a = 1.0 - c - b;
+
+        (3.0 * a * t + 2.0 * b) * t + c
+    }
+
+    /// Finds `t` for `x` with Newton's method and falls back to bisection.
+    fn solve_x(&self, x: f64) -> f64 {
+        let mut t = x;
+        for _ in 0..NEWTON_ITERATIONS {
+            let error = Self::sample(self.x1, self.x2, t) - x;
+            if error.abs() < EPSILON {
+                return t;
+            }
+
+            let derivative = Self::sample_derivative(self.x1, self.x2, t);
+            if derivative.abs() < EPSILON {
+                break;
+            }
+            t -= error / derivative;
+        }
+
+        let (mut lower, mut upper) = (0.0, 1.0);
+        t = x;
+        for _ in 0..BISECTION_ITERATIONS {
+            let value = Self::sample(self.x1, self.x2, t);
+            if (value - x).abs() < EPSILON {
+                break;
+            }
+
+            match value < x {
+                true => lower = t,
+                false => upper = t,
+            }
+            t = (lower + upper) / 2.0;
+        }
+
+        t
+    }
+
+    /// Value of the curve at `x` between `0.0` and `1.0`.
+    pub fn value(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        if x >= 1.0 {
+            return 1.0;
+        }
+
+        Self::sample(self.y1, self.y2, self.solve_x(x))
+    }
+}
+
+impl Function for CubicBezier {
+    fn delta_ease_in(&self, delta: f32) -> f32 {
+        self.value(delta as f64) as f32
+    }
+
+    fn delta_ease_out(&self, delta: f32) -> f32 {
+        super::ease_out_from_ease_in(|t| self.delta_ease_in(t), delta)
+    }
+
+    fn delta_ease_in_out(&self, delta: f32) -> f32 {
+        super::ease_in_out_from_ease_in(|t| self.delta_ease_in(t), delta)
+    }
+}
@@ -1,3 +1,26 @@
+pub mod cubic_bezier;
+#[cfg(feature = "scripting")]
+pub mod script;
+mod steps;
+
+pub use self::cubic_bezier::CubicBezier;
+#[cfg(feature = "scripting")]
+pub use self::script::ScriptEasing;
+use self::steps::Steps;
+
+/// Mirrors an ease in function: `1 - f(1 - t)`.
+fn ease_out_from_ease_in(ease_in: impl Fn(f32) -> f32, delta: f32) -> f32 {
+    1.0 - ease_in(1.0 - delta)
+}
+
+/// Uses the ease in function for the first half and the mirrored function for the second half.
+fn ease_in_out_from_ease_in(ease_in: impl Fn(f32) -> f32, delta: f32) -> f32 {
+    match delta < 0.5 {
+        true => ease_in(delta * 2.0) / 2.0,
+        false => 1.0 - ease_in((1.0 - delta) * 2.0) / 2.0,
+    }
+}
+
 macro_rules! as_item {
     ($i:item) => {
         $i
@@ -40,10 +63,23 @@ macro_rules! generate_ease_struct {
     };
     ($($x:ident),+ $(,)?) => (
         as_item! {
+            /// The easing functions of [easer](https://docs.rs/easer) and custom easings.
             #[derive(std::fmt::Debug)]
             #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
             #[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
-            pub enum FunctionType { $($x),* }
+            pub enum FunctionType {
+                $($x,)*
+
+                /// Css `cubic-bezier(x1, y1, x2, y2)`
+                CubicBezier(CubicBezier),
+
+                /// Css `steps(n, jump-end)`, jumps in `n` equal steps
+                Steps(u32),
+
+                /// Custom easing as rhai expression
+                #[cfg(feature = "scripting")]
+                Script(ScriptEasing),
+            }
         }
 
         impl FunctionType {
@@ -54,34 +90,34 @@ macro_rules! generate_ease_struct {
                 )*
             }
 
+            /// Calls `f` with the function of the variant, custom easings like [`FunctionType::Steps`]
+            /// only exist as long as the call.
             #[inline(always)]
-            fn get_struct(&self) -> &'static dyn crate::animation::Function {
+            fn with_function<R>(&self, f: impl FnOnce(&dyn crate::animation::Function) -> R) -> R {
                 match self {
                     $(
                         Self::$x =>
                             paste::paste! {
-                                & Self::[<CONST_ $x>]
+                                f(& Self::[<CONST_ $x>])
                             },
                     )*
+                    Self::CubicBezier(cubic_bezier) => f(cubic_bezier),
+                    Self::Steps(count) => f(&Steps::new(*count)),
+                    #[cfg(feature = "scripting")]
+                    Self::Script(script) => f(script),
                 }
             }
 
             pub fn delta_ease_in(&self, delta: f32) -> f32 {
-                let function = self.get_struct();
-
-                function.delta_ease_in(delta)
+                self.with_function(|function| function.delta_ease_in(delta))
             }
 
             pub fn delta_ease_out(&self, delta: f32) -> f32 {
-                let function = self.get_struct();
-
-                function.delta_ease_out(delta)
+                self.with_function(|function| function.delta_ease_out(delta))
             }
 
             pub fn delta_ease_in_out(&self, delta: f32) -> f32 {
-                let function = self.get_struct();
-
-                function.delta_ease_in_out(delta)
+                self.with_function(|function| function.delta_ease_in_out(delta))
             }
 
             pub fn delta(&self, ease: crate::animation::EaseType, delta: f32) -> f32 {
@@ -100,3 +136,138 @@ macro_rules! generate_ease_struct {
 }
 
 generate_ease_struct!(Back, Bounce, Circ, Cubic, Elastic, Expo, Linear, Quad, Quart, Quint, Sine);
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+    use crate::animation::EaseType;
+
+    #[test]
+    fn cubic_bezier() {
+        let linear = FunctionType::CubicBezier(CubicBezier::new(0.0, 0.0, 1.0, 1.0));
+        for i in 0..=10 {
+            let t = i as f32 / 10.0;
+            assert_abs_diff_eq!(linear.delta(EaseType::In, t), t, epsilon = 0.0001);
+        }
+
+        // values of the css `ease-in-out` curve
+        let ease_in_out = FunctionType::CubicBezier(CubicBezier::EASE_IN_OUT);
+        assert_abs_diff_eq!(ease_in_out.delta(EaseType::In, 0.5), 0.5, epsilon = 0.0001);
+        assert_abs_diff_eq!(
+            ease_in_out.delta(EaseType::In, 0.25),
+            0.1291,
+            epsilon = 0.001
+        );
+
+        // css `ease-in` mirrored is `ease-out`
+        let ease_in = FunctionType::CubicBezier(CubicBezier::EASE_IN);
+        let ease_out = FunctionType::CubicBezier(CubicBezier::EASE_OUT);
+        for i in 0..=10 {
+            let t = i as f32 / 10.0;
+            assert_abs_diff_eq!(
+                ease_in.delta(EaseType::Out, t),
+                ease_out.delta(EaseType::In, t),
+                epsilon = 0.0001
+            );
+        }
+    }
+
+    #[test]
+    fn cubic_bezier_can_overshoot() {
+        let back = CubicBezier::new(0.5, -0.5, 0.5, 1.5);
+
+        assert!(back.value(0.1) < 0.0);
+        assert!(back.value(0.9) > 1.0);
+        assert_eq!(back.value(1.0), 1.0);
+    }
+
+    #[test]
+    fn steps() {
+        let steps = FunctionType::Steps(4);
+
+        assert_eq!(steps.delta(EaseType::In, 0.0), 0.0);
+        assert_eq!(steps.delta(EaseType::In, 0.24), 0.0);
+        assert_eq!(steps.delta(EaseType::In, 0.25), 0.25);
+        assert_eq!(steps.delta(EaseType::In, 0.99), 0.75);
+        assert_eq!(steps.delta(EaseType::In, 1.0), 1.0);
+
+        // jumps at the start
+        assert_eq!(steps.delta(EaseType::Out, 0.01), 0.25);
+        assert_eq!(steps.delta(EaseType::Out, 0.0), 0.0);
+
+        assert_eq!(FunctionType::Steps(0).delta(EaseType::In, 0.5), 0.0);
+    }
+
+    #[cfg(feature = "scripting")]
+    #[test]
+    fn script() {
+        let quad = FunctionType::Script(ScriptEasing::new("t * t").unwrap());
+
+        assert_abs_diff_eq!(quad.delta(EaseType::In, 0.5), 0.25);
+        assert_abs_diff_eq!(quad.delta(EaseType::Out, 0.5), 0.75);
+        assert_abs_diff_eq!(
+            quad.delta(EaseType::In, 0.3),
+            FunctionType::Quad.delta(EaseType::In, 0.3),
+            epsilon = 0.0001
+        );
+
+        let constant = FunctionType::Script(ScriptEasing::new("1").unwrap());
+        assert_eq!(constant.delta(EaseType::In, 0.5), 1.0);
+
+        assert!(ScriptEasing::new("t *").is_err());
+        // too deeply nested
+        assert!(ScriptEasing::new(format!("{}t{}", "(".repeat(100), ")".repeat(100))).is_err());
+    }
+
+    #[cfg(feature = "scripting")]
+    #[test]
+    fn script_falls_back_to_linear() {
+        let invalid = FunctionType::Script(ScriptEasing::new("unknown_function(t)").unwrap());
+
+        assert_eq!(invalid.delta(EaseType::In, 0.3), 0.3);
+
+        let runaway = FunctionType::Script(
+            ScriptEasing::new("range(0, 1_000_000_000).reduce(|sum, i| sum + i)").unwrap(),
+        );
+        assert_eq!(runaway.delta(EaseType::In, 0.3), 0.3);
+    }
+
+    #[cfg(all(feature = "remote_renderer", feature = "deserialize"))]
+    #[test]
+    fn clamps_deserialized_cubic_bezier() {
+        let yaml = "{ x1: -1.0, y1: 2.0, x2: 3.0, y2: -0.5 }";
+        let imported: CubicBezier = serde_yaml::from_str(yaml).unwrap();
+
+        assert_eq!(imported, CubicBezier::new(0.0, 2.0, 1.0, -0.5));
+    }
+
+    #[cfg(all(
+        feature = "remote_renderer",
+        feature = "deserialize",
+        feature = "scripting"
+    ))]
+    #[test]
+    fn survives_yaml() {
+        let functions = vec![
+            FunctionType::Sine,
+            FunctionType::CubicBezier(CubicBezier::EASE),
+            FunctionType::Steps(3),
+            FunctionType::Script(ScriptEasing::new("t * t").unwrap()),
+        ];
+
+        let yaml = serde_yaml::to_string(&functions).unwrap();
+        let imported: Vec<FunctionType> = serde_yaml::from_str(&yaml).unwrap();
+
+        for (function, imported) in functions.iter().zip(imported.iter()) {
+            for i in 0..=10 {
+                let t = i as f32 / 10.0;
+                assert_eq!(
+                    function.delta(EaseType::InOut, t),
+                    imported.delta(EaseType::InOut, t)
+                );
+            }
+        }
+    }
+}
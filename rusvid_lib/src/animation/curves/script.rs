@@ -0,0 +1,101 @@
+use std::cell::OnceCell;
+
+use rhai::{Dynamic, Engine, Scope, AST};
+
+use crate::animation::Function;
+
+/// Easing defined by a [rhai](https://rhai.rs/) expression, the variable `t` holds the progress
+/// between `0.0` and `1.0`, e.g. `"t * t"` or `"1.0 - (1.0 - t) ** 3"`.
+///
+/// The expression is used as given for [`crate::animation::EaseType::In`], the other ease types
+/// are derived like for [`super::cubic_bezier::CubicBezier`]. Only the source is serialized, the
+/// expression is compiled at the first use. If the expression can't be evaluated or exceeds the
+/// limits of the engine, e.g. because it runs too long, the progress is used unchanged.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct ScriptEasing {
+    script: String,
+
+    #[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(skip))]
+    compiled: OnceCell<Option<Box<(Engine, AST)>>>,
+}
+
+impl std::fmt::Debug for ScriptEasing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ScriptEasing")
+            .field("script", &self.script)
+            .finish()
+    }
+}
+
+/// Engine with limits, so an expression can't block or exhaust the renderer.
+fn engine() -> Engine {
+    let mut engine = Engine::new();
+    engine.set_max_operations(10_000);
+    engine.set_max_string_size(1_000);
+    engine.set_max_array_size(1_000);
+    engine.set_max_expr_depths(32, 32);
+
+    engine
+}
+
+impl ScriptEasing {
+    /// Creates the easing and checks that the expression compiles.
+    pub fn new(script: impl Into<String>) -> Result<Self, rhai::ParseError> {
+        let script = script.into();
+        engine().compile_expression(&script)?;
+
+        Ok(ScriptEasing {
+            script,
+            compiled: OnceCell::new(),
+        })
+    }
+
+    pub fn script(&self) -> &str {
+        &self.script
+    }
+
+    fn evaluate(&self, t: f32) -> Option<f32> {
+        let (engine, ast) = &**self
+            .compiled
+            .get_or_init(|| {
+                let engine = engine();
+                match engine.compile_expression(&self.script) {
+                    Ok(ast) => Some(Box::new((engine, ast))),
+                    Err(err) => {
+                        tracing::error!("can't compile easing script: {err}");
+                        None
+                    }
+                }
+            })
+            .as_ref()?;
+
+        let mut scope = Scope::new();
+        scope.push("t", t as rhai::FLOAT);
+
+        let value = engine
+            .eval_ast_with_scope::<Dynamic>(&mut scope, ast)
+            .map_err(|err| tracing::error!("can't evaluate easing script: {err}"))
+            .ok()?;
+
+        value
+            .as_float()
+            .or_else(|_| value.as_int().map(|value| value as rhai::FLOAT))
+            .map(|value| value as f32)
+            .ok()
+    }
+}
+
+impl Function for ScriptEasing {
+    fn delta_ease_in(&self, delta: f32) -> f32 {
+        self.evaluate(delta).unwrap_or(delta)
+    }
+
+    fn delta_ease_out(&self, delta: f32) -> f32 {
+        super::ease_out_from_ease_in(|t| self.delta_ease_in(t), delta)
+    }
+
+    fn delta_ease_in_out(&self, delta: f32) -> f32 {
+        super::ease_in_out_from_ease_in(|t| self.delta_ease_in(t), delta)
+    }
+}
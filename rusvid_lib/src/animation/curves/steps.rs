@@ -0,0 +1,34 @@
+use crate::animation::Function;
+
+/// Easing which jumps in `count` equal steps like the css function `steps(count, jump-end)`.
+#[derive(Debug)]
+pub(crate) struct Steps {
+    count: u32,
+}
+
+impl Steps {
+    pub fn new(count: u32) -> Self {
+        Steps {
+            count: count.max(1),
+        }
+    }
+}
+
+impl Function for Steps {
+    fn delta_ease_in(&self, delta: f32) -> f32 {
+        if delta >= 1.0 {
+            return 1.0;
+        }
+
+        let count = self.count as f32;
+        (delta * count).floor().max(0.0) / count
+    }
+
+    fn delta_ease_out(&self, delta: f32) -> f32 {
+        super::ease_out_from_ease_in(|t| self.delta_ease_in(t), delta)
+    }
+
+    fn delta_ease_in_out(&self, delta: f32) -> f32 {
+        super::ease_in_out_from_ease_in(|t| self.delta_ease_in(t), delta)
+    }
+}
@@ -0,0 +1,93 @@
+use rusvid_core::holder::gradient::base::BaseGradient;
+use rusvid_core::holder::likes::ColorLike;
+
+use super::{Animation, EaseType, FunctionType, Range};
+use crate::types::FPS;
+
+/// Rotates a gradient around its center and shifts its stops, all other values are taken from
+/// `gradient`. A solid color isn't changed.
+///
+/// The rotation is in radiant, the shift is added to the offsets of the stops of `gradient`, see
+/// [`BaseGradient::shift_stops`].
+#[derive(Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct GradientAnimation {
+    object_id: String,
+
+    curve: FunctionType,
+    ease: EaseType,
+
+    frame_range: Range,
+
+    gradient: ColorLike,
+    start_rotation: f64,
+    end_rotation: f64,
+    start_shift: f64,
+    end_shift: f64,
+}
+
+impl GradientAnimation {
+    pub fn new<I: Into<String> + Clone>(
+        id: &I,
+        frames: impl Into<Range>,
+        gradient: ColorLike,
+        rotations: (f64, f64),
+        shifts: (f64, f64),
+        curve: FunctionType,
+        ease: EaseType,
+    ) -> Self {
+        Self {
+            curve,
+            ease,
+            object_id: id.clone().into(),
+            frame_range: frames.into(),
+            gradient,
+            start_rotation: rotations.0,
+            end_rotation: rotations.1,
+            start_shift: shifts.0,
+            end_shift: shifts.1,
+        }
+    }
+}
+
+impl Animation for GradientAnimation {
+    fn object_id(&self) -> &str {
+        &self.object_id
+    }
+
+    type OUTPUT = ColorLike;
+    fn get_value(&self, frame: usize) -> Self::OUTPUT {
+        let percentage = self.frame_range.percentage(frame);
+        let delta = self.curve.delta(self.ease, percentage) as f64;
+
+        let rotation = self.start_rotation + (self.end_rotation - self.start_rotation) * delta;
+        let shift = self.start_shift + (self.end_shift - self.start_shift) * delta;
+
+        let apply = |base: &mut BaseGradient| {
+            base.set_rotation(rotation);
+            base.shift_stops(shift);
+        };
+
+        let mut gradient = self.gradient.clone();
+        match &mut gradient {
+            ColorLike::Color(_) => (),
+            ColorLike::LinearGradient(l_g) => apply(l_g.base_mut()),
+            ColorLike::RadialGradient(r_g) => apply(r_g.base_mut()),
+        }
+
+        gradient
+    }
+
+    fn start_frame(&self) -> usize {
+        self.frame_range.start()
+    }
+
+    fn end_frame(&self) -> usize {
+        self.frame_range.end_bound()
+    }
+
+    fn resolve_time(&mut self, framerate: FPS) {
+        self.frame_range.resolve(framerate);
+    }
+}
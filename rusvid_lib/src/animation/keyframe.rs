@@ -0,0 +1,410 @@
+use std::time::Duration;
+
+use rusvid_core::holder::likes::ColorLike;
+use rusvid_core::holder::transform::Transform;
+use rusvid_core::pixel::Pixel;
+use rusvid_core::point::Point;
+
+use super::range::{frame_at, DEFAULT_FRAMERATE};
+use super::{Animation, EaseType, FunctionType};
+use crate::types::FPS;
+
+/// Values which can be interpolated by a [`KeyframeTrack`].
+pub trait Interpolate: Clone {
+    /// `0.0` returns `self` and `1.0` returns `other`, some easing functions overshoot and
+    /// therefore `t` can be outside of this range.
+    fn interpolate(&self, other: &Self, t: f64) -> Self;
+}
+
+impl Interpolate for f64 {
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Interpolate for Point {
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        *self + (*other - *self) * t
+    }
+}
+
+impl Interpolate for Pixel {
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        Pixel::interpolate(self, other, t)
+    }
+}
+
+impl Interpolate for ColorLike {
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        ColorLike::interpolate(self, other, t)
+    }
+}
+
+/// A value at a frame, `curve` and `ease` are used from this key to the next key.
+#[derive(Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct Keyframe<T> {
+    frame: usize,
+    value: T,
+
+    curve: FunctionType,
+    ease: EaseType,
+
+    /// Time of the key, the frame is calculated from it by [`Keyframe::resolve_time`]
+    #[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(default))]
+    time: Option<Duration>,
+}
+
+impl<T> Keyframe<T> {
+    pub fn new(frame: usize, value: T, curve: FunctionType, ease: EaseType) -> Self {
+        Keyframe {
+            frame,
+            value,
+            curve,
+            ease,
+            time: None,
+        }
+    }
+
+    /// A key in time, the frame is calculated with the framerate of the composition when the
+    /// composition is updated, see [`super::Range::from_time`].
+    pub fn from_time(time: Duration, value: T, curve: FunctionType, ease: EaseType) -> Self {
+        let mut key = Keyframe {
+            time: Some(time),
+            ..Keyframe::new(0, value, curve, ease)
+        };
+        key.resolve_time(DEFAULT_FRAMERATE);
+
+        key
+    }
+
+    pub fn frame(&self) -> usize {
+        self.frame
+    }
+
+    /// Time of the key if the key isn't based on a frame.
+    pub fn time(&self) -> Option<Duration> {
+        self.time
+    }
+
+    /// Calculates the frame of a key in time with `framerate`, keys based on a frame aren't
+    /// changed.
+    pub fn resolve_time(&mut self, framerate: FPS) {
+        if let Some(time) = self.time {
+            self.frame = frame_at(time, framerate);
+        }
+    }
+
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+}
+
+/// A list of keys which are evaluated per frame, between two keys the value is interpolated with
+/// the easing of the first key. Before the first key the value of the first key is used and after
+/// the last key the value of the last key.
+///
+/// ```rust
+/// use rusvid_lib::animation::keyframe::KeyframeTrack;
+/// use rusvid_lib::animation::{EaseType, FunctionType};
+///
+/// let track = KeyframeTrack::new()
+///     .with_key(0, 0.0, FunctionType::Linear, EaseType::In)
+///     .with_key(10, 1.0, FunctionType::Linear, EaseType::In)
+///     .with_key(20, 0.5, FunctionType::Linear, EaseType::In);
+///
+/// assert_eq!(track.value_at(5), Some(0.5));
+/// assert_eq!(track.value_at(15), Some(0.75));
+/// assert_eq!(track.value_at(30), Some(0.5));
+/// ```
+#[derive(Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct KeyframeTrack<T> {
+    keys: Vec<Keyframe<T>>,
+}
+
+impl<T> Default for KeyframeTrack<T> {
+    fn default() -> Self {
+        KeyframeTrack { keys: Vec::new() }
+    }
+}
+
+impl<T: Interpolate> KeyframeTrack<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the key, the keys are kept sorted by their frame and a key at the same frame is
+    /// replaced.
+    pub fn add_key(&mut self, key: Keyframe<T>) {
+        match self.keys.binary_search_by_key(&key.frame, |k| k.frame) {
+            Ok(index) => self.keys[index] = key,
+            Err(index) => self.keys.insert(index, key),
+        }
+    }
+
+    pub fn with_key(mut self, frame: usize, value: T, curve: FunctionType, ease: EaseType) -> Self {
+        self.add_key(Keyframe::new(frame, value, curve, ease));
+        self
+    }
+
+    /// Adds a key in time, see [`Keyframe::from_time`].
+    pub fn with_key_at(
+        mut self,
+        time: Duration,
+        value: T,
+        curve: FunctionType,
+        ease: EaseType,
+    ) -> Self {
+        self.add_key(Keyframe::from_time(time, value, curve, ease));
+        self
+    }
+
+    /// Calculates the frames of all keys in time with `framerate`, see
+    /// [`Keyframe::resolve_time`].
+    pub fn resolve_time(&mut self, framerate: FPS) {
+        for key in &mut self.keys {
+            key.resolve_time(framerate);
+        }
+
+        // keys at the same time stay in order
+        self.keys.sort_by_key(|key| key.frame);
+    }
+
+    pub fn keys(&self) -> &[Keyframe<T>] {
+        &self.keys
+    }
+
+    pub fn first_frame(&self) -> Option<usize> {
+        self.keys.first().map(Keyframe::frame)
+    }
+
+    pub fn last_frame(&self) -> Option<usize> {
+        self.keys.last().map(Keyframe::frame)
+    }
+
+    /// Returns the value at `frame`, `None` if the track has no keys.
+    pub fn value_at(&self, frame: usize) -> Option<T> {
+        let index = self.keys.partition_point(|key| key.frame <= frame);
+
+        match (index.checked_sub(1), self.keys.get(index)) {
+            (None, _) => self.keys.first().map(|key| key.value.clone()),
+            (Some(previous), None) => Some(self.keys[previous].value.clone()),
+            (Some(previous), Some(next)) => {
+                let previous = &self.keys[previous];
+
+                let percentage =
+                    (frame - previous.frame) as f32 / (next.frame - previous.frame) as f32;
+                let t = previous.curve.delta(previous.ease, percentage) as f64;
+
+                Some(previous.value.interpolate(&next.value, t))
+            }
+        }
+    }
+}
+
+/// The property of an object which is animated by a [`KeyframeAnimation`].
+#[derive(Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub enum KeyframeProperty {
+    /// [`Transform::Position`]
+    Position(KeyframeTrack<Point>),
+    /// [`Transform::Color`] with a solid color
+    Color(KeyframeTrack<Pixel>),
+    /// [`Transform::Color`] with colors or gradients, see [`ColorLike::interpolate`]
+    Paint(KeyframeTrack<ColorLike>),
+    /// [`Transform::Opacity`]
+    Opacity(KeyframeTrack<f64>),
+    /// [`Transform::Rotate`]
+    Rotation(KeyframeTrack<f64>),
+    /// [`Transform::Scale`]
+    Scale(KeyframeTrack<Point>),
+}
+
+impl KeyframeProperty {
+    fn first_frame(&self) -> Option<usize> {
+        match self {
+            KeyframeProperty::Position(track) => track.first_frame(),
+            KeyframeProperty::Color(track) => track.first_frame(),
+            KeyframeProperty::Paint(track) => track.first_frame(),
+            KeyframeProperty::Opacity(track) => track.first_frame(),
+            KeyframeProperty::Rotation(track) => track.first_frame(),
+            KeyframeProperty::Scale(track) => track.first_frame(),
+        }
+    }
+
+    fn last_frame(&self) -> Option<usize> {
+        match self {
+            KeyframeProperty::Position(track) => track.last_frame(),
+            KeyframeProperty::Color(track) => track.last_frame(),
+            KeyframeProperty::Paint(track) => track.last_frame(),
+            KeyframeProperty::Opacity(track) => track.last_frame(),
+            KeyframeProperty::Rotation(track) => track.last_frame(),
+            KeyframeProperty::Scale(track) => track.last_frame(),
+        }
+    }
+
+    fn resolve_time(&mut self, framerate: FPS) {
+        match self {
+            KeyframeProperty::Position(track) => track.resolve_time(framerate),
+            KeyframeProperty::Color(track) => track.resolve_time(framerate),
+            KeyframeProperty::Paint(track) => track.resolve_time(framerate),
+            KeyframeProperty::Opacity(track) => track.resolve_time(framerate),
+            KeyframeProperty::Rotation(track) => track.resolve_time(framerate),
+            KeyframeProperty::Scale(track) => track.resolve_time(framerate),
+        }
+    }
+}
+
+/// Animates a property of an object with a [`KeyframeTrack`], the animation runs from the first
+/// to the last key.
+#[derive(Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct KeyframeAnimation {
+    object_id: String,
+
+    property: KeyframeProperty,
+}
+
+impl KeyframeAnimation {
+    pub fn new<I: Into<String> + Clone>(id: &I, property: KeyframeProperty) -> Self {
+        Self {
+            object_id: id.clone().into(),
+            property,
+        }
+    }
+
+    pub fn property(&self) -> &KeyframeProperty {
+        &self.property
+    }
+}
+
+impl Animation for KeyframeAnimation {
+    fn object_id(&self) -> &str {
+        &self.object_id
+    }
+
+    /// `None` if the track has no keys.
+    type OUTPUT = Option<Transform>;
+    fn get_value(&self, frame: usize) -> Self::OUTPUT {
+        match &self.property {
+            KeyframeProperty::Position(track) => track.value_at(frame).map(Transform::Position),
+            KeyframeProperty::Color(track) => track
+                .value_at(frame)
+                .map(|color| Transform::Color(Some(ColorLike::Color(color)))),
+            KeyframeProperty::Paint(track) => track
+                .value_at(frame)
+                .map(|color_like| Transform::Color(Some(color_like))),
+            KeyframeProperty::Opacity(track) => track.value_at(frame).map(Transform::Opacity),
+            KeyframeProperty::Rotation(track) => track.value_at(frame).map(Transform::Rotate),
+            KeyframeProperty::Scale(track) => track.value_at(frame).map(Transform::Scale),
+        }
+    }
+
+    fn start_frame(&self) -> usize {
+        self.property.first_frame().unwrap_or(0)
+    }
+
+    fn end_frame(&self) -> usize {
+        self.property
+            .last_frame()
+            .map(|frame| frame + 1)
+            .unwrap_or(0)
+    }
+
+    fn resolve_time(&mut self, framerate: FPS) {
+        self.property.resolve_time(framerate);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn track() -> KeyframeTrack<Point> {
+        KeyframeTrack::new()
+            .with_key(
+                10,
+                Point::new(10.0, 0.0),
+                FunctionType::Linear,
+                EaseType::In,
+            )
+            .with_key(0, Point::ZERO, FunctionType::Linear, EaseType::In)
+            .with_key(20, Point::new(10.0, 20.0), FunctionType::Quad, EaseType::In)
+            .with_key(30, Point::ZERO, FunctionType::Linear, EaseType::In)
+    }
+
+    #[test]
+    fn keys_are_sorted() {
+        let frames: Vec<usize> = track().keys().iter().map(Keyframe::frame).collect();
+
+        assert_eq!(frames, vec![0, 10, 20, 30]);
+    }
+
+    #[test]
+    fn replaces_key_at_same_frame() {
+        let track = track().with_key(10, Point::ONE, FunctionType::Linear, EaseType::In);
+
+        assert_eq!(track.keys().len(), 4);
+        assert_eq!(track.value_at(10), Some(Point::ONE));
+    }
+
+    #[test]
+    fn interpolates_between_keys() {
+        let track = track();
+
+        assert_eq!(track.value_at(0), Some(Point::ZERO));
+        assert_eq!(track.value_at(5), Some(Point::new(5.0, 0.0)));
+        assert_eq!(track.value_at(10), Some(Point::new(10.0, 0.0)));
+        assert_eq!(track.value_at(15), Some(Point::new(10.0, 10.0)));
+        // quad ease in from the key at frame 20
+        assert_eq!(track.value_at(25), Some(Point::new(7.5, 15.0)));
+    }
+
+    #[test]
+    fn holds_the_first_and_last_value() {
+        let track = KeyframeTrack::new()
+            .with_key(5, 1.0, FunctionType::Linear, EaseType::In)
+            .with_key(10, 2.0, FunctionType::Linear, EaseType::In);
+
+        assert_eq!(track.value_at(0), Some(1.0));
+        assert_eq!(track.value_at(100), Some(2.0));
+        assert_eq!(KeyframeTrack::<f64>::new().value_at(0), None);
+    }
+
+    #[test]
+    fn keys_in_time() {
+        let mut track = KeyframeTrack::new()
+            .with_key_at(
+                Duration::from_secs(1),
+                1.0,
+                FunctionType::Linear,
+                EaseType::In,
+            )
+            .with_key_at(Duration::ZERO, 0.0, FunctionType::Linear, EaseType::In)
+            .with_key(45, 2.0, FunctionType::Linear, EaseType::In);
+        assert_eq!(track.first_frame(), Some(0));
+        assert_eq!(track.value_at(15), Some(0.5));
+
+        // the key at a frame doesn't move and is now before the key at one second
+        track.resolve_time(60);
+        let frames: Vec<usize> = track.keys().iter().map(Keyframe::frame).collect();
+        assert_eq!(frames, vec![0, 45, 60]);
+        assert_eq!(track.keys()[2].time(), Some(Duration::from_secs(1)));
+        assert_eq!(track.value_at(60), Some(1.0));
+    }
+
+    #[test]
+    fn animation_runs_from_first_to_last_key() {
+        let animation = KeyframeAnimation::new(&"id", KeyframeProperty::Position(track()));
+
+        assert_eq!(animation.start_frame(), 0);
+        assert_eq!(animation.end_frame(), 31);
+        assert!(animation.status_running(30));
+        assert!(animation.status_finish(31));
+    }
+}
@@ -1,15 +1,36 @@
 pub mod change_color_animation;
+pub mod change_gradient_animation;
 pub mod curves;
+pub mod gradient_animation;
+pub mod keyframe;
+pub mod morph_animation;
+pub mod motion_path_animation;
+pub mod opacity_animation;
 pub mod position_animation;
+pub mod rotate_animation;
+pub mod scale_animation;
 pub mod set_color_animation;
+pub mod stroke_animation;
+pub mod trim_animation;
 
 mod range;
 
 use self::change_color_animation::ChangeColorAnimation;
+use self::change_gradient_animation::ChangeGradientAnimation;
 pub use self::curves::FunctionType;
+use self::gradient_animation::GradientAnimation;
+use self::keyframe::KeyframeAnimation;
+use self::morph_animation::MorphAnimation;
+use self::motion_path_animation::MotionPathAnimation;
+use self::opacity_animation::OpacityAnimation;
 use self::position_animation::PositionAnimation;
 pub use self::range::{Range, RangeType};
+use self::rotate_animation::RotateAnimation;
+use self::scale_animation::ScaleAnimation;
 use self::set_color_animation::SetColorAnimation;
+use self::stroke_animation::StrokeAnimation;
+use self::trim_animation::TrimAnimation;
+use crate::types::FPS;
 
 #[derive(Debug)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
@@ -18,6 +39,16 @@ pub enum AnimationType {
     Position(PositionAnimation),
     SetColor(SetColorAnimation),
     ChangeColor(ChangeColorAnimation),
+    Opacity(OpacityAnimation),
+    Stroke(StrokeAnimation),
+    Trim(TrimAnimation),
+    Morph(MorphAnimation),
+    Gradient(GradientAnimation),
+    ChangeGradient(ChangeGradientAnimation),
+    Keyframe(KeyframeAnimation),
+    MotionPath(MotionPathAnimation),
+    Rotate(RotateAnimation),
+    Scale(ScaleAnimation),
 }
 
 impl AnimationType {
@@ -26,6 +57,16 @@ impl AnimationType {
             (AnimationType::Position(_), AnimationType::Position(_)) => true,
             (AnimationType::SetColor(_), AnimationType::SetColor(_)) => true,
             (AnimationType::ChangeColor(_), AnimationType::ChangeColor(_)) => true,
+            (AnimationType::Opacity(_), AnimationType::Opacity(_)) => true,
+            (AnimationType::Stroke(_), AnimationType::Stroke(_)) => true,
+            (AnimationType::Trim(_), AnimationType::Trim(_)) => true,
+            (AnimationType::Morph(_), AnimationType::Morph(_)) => true,
+            (AnimationType::Gradient(_), AnimationType::Gradient(_)) => true,
+            (AnimationType::ChangeGradient(_), AnimationType::ChangeGradient(_)) => true,
+            (AnimationType::Keyframe(_), AnimationType::Keyframe(_)) => true,
+            (AnimationType::MotionPath(_), AnimationType::MotionPath(_)) => true,
+            (AnimationType::Rotate(_), AnimationType::Rotate(_)) => true,
+            (AnimationType::Scale(_), AnimationType::Scale(_)) => true,
             _ => false,
         }
     }
@@ -37,6 +78,16 @@ impl Animation for AnimationType {
             AnimationType::Position(animation) => animation.object_id(),
             AnimationType::SetColor(animation) => animation.object_id(),
             AnimationType::ChangeColor(animation) => animation.object_id(),
+            AnimationType::Opacity(animation) => animation.object_id(),
+            AnimationType::Stroke(animation) => animation.object_id(),
+            AnimationType::Trim(animation) => animation.object_id(),
+            AnimationType::Morph(animation) => animation.object_id(),
+            AnimationType::Gradient(animation) => animation.object_id(),
+            AnimationType::ChangeGradient(animation) => animation.object_id(),
+            AnimationType::Keyframe(animation) => animation.object_id(),
+            AnimationType::MotionPath(animation) => animation.object_id(),
+            AnimationType::Rotate(animation) => animation.object_id(),
+            AnimationType::Scale(animation) => animation.object_id(),
         }
     }
 
@@ -50,6 +101,16 @@ impl Animation for AnimationType {
             AnimationType::Position(animation) => animation.start_frame(),
             AnimationType::SetColor(animation) => animation.start_frame(),
             AnimationType::ChangeColor(animation) => animation.start_frame(),
+            AnimationType::Opacity(animation) => animation.start_frame(),
+            AnimationType::Stroke(animation) => animation.start_frame(),
+            AnimationType::Trim(animation) => animation.start_frame(),
+            AnimationType::Morph(animation) => animation.start_frame(),
+            AnimationType::Gradient(animation) => animation.start_frame(),
+            AnimationType::ChangeGradient(animation) => animation.start_frame(),
+            AnimationType::Keyframe(animation) => animation.start_frame(),
+            AnimationType::MotionPath(animation) => animation.start_frame(),
+            AnimationType::Rotate(animation) => animation.start_frame(),
+            AnimationType::Scale(animation) => animation.start_frame(),
         }
     }
 
@@ -58,6 +119,34 @@ impl Animation for AnimationType {
             AnimationType::Position(animation) => animation.end_frame(),
             AnimationType::SetColor(animation) => animation.end_frame(),
             AnimationType::ChangeColor(animation) => animation.end_frame(),
+            AnimationType::Opacity(animation) => animation.end_frame(),
+            AnimationType::Stroke(animation) => animation.end_frame(),
+            AnimationType::Trim(animation) => animation.end_frame(),
+            AnimationType::Morph(animation) => animation.end_frame(),
+            AnimationType::Gradient(animation) => animation.end_frame(),
+            AnimationType::ChangeGradient(animation) => animation.end_frame(),
+            AnimationType::Keyframe(animation) => animation.end_frame(),
+            AnimationType::MotionPath(animation) => animation.end_frame(),
+            AnimationType::Rotate(animation) => animation.end_frame(),
+            AnimationType::Scale(animation) => animation.end_frame(),
+        }
+    }
+
+    fn resolve_time(&mut self, framerate: FPS) {
+        match self {
+            AnimationType::Position(animation) => animation.resolve_time(framerate),
+            AnimationType::SetColor(animation) => animation.resolve_time(framerate),
+            AnimationType::ChangeColor(animation) => animation.resolve_time(framerate),
+            AnimationType::Opacity(animation) => animation.resolve_time(framerate),
+            AnimationType::Stroke(animation) => animation.resolve_time(framerate),
+            AnimationType::Trim(animation) => animation.resolve_time(framerate),
+            AnimationType::Morph(animation) => animation.resolve_time(framerate),
+            AnimationType::Gradient(animation) => animation.resolve_time(framerate),
+            AnimationType::ChangeGradient(animation) => animation.resolve_time(framerate),
+            AnimationType::Keyframe(animation) => animation.resolve_time(framerate),
+            AnimationType::MotionPath(animation) => animation.resolve_time(framerate),
+            AnimationType::Rotate(animation) => animation.resolve_time(framerate),
+            AnimationType::Scale(animation) => animation.resolve_time(framerate),
         }
     }
 }
@@ -89,6 +178,12 @@ pub trait Animation: std::fmt::Debug {
     /// Animation duration: [start_frame, end_frame)
     fn end_frame(&self) -> usize;
 
+    /// Calculates the frames of ranges in time with the framerate of the composition, it's
+    /// called by [`crate::composition::Composition::update`] before each frame. See
+    /// [`Range::resolve`].
+    #[allow(unused_variables)]
+    fn resolve_time(&mut self, framerate: FPS) {}
+
     /// Returns `true` if the animation hasn't started yet, otherwise `false`.
     fn status_pending(&self, frame_count: usize) -> bool {
         frame_count < self.start_frame()
@@ -0,0 +1,67 @@
+use rusvid_core::holder::likes::{PathLike, PathMorph};
+
+use super::{Animation, EaseType, FunctionType, Range};
+use crate::types::FPS;
+
+/// Morphs the path of an item into another path, e.g. a circle from
+/// [`crate::figures::circle::circle`] into a rect from [`crate::figures::rect::rect`].
+///
+/// Both paths are normalised to the same commands with [`PathMorph`] and interpolated point by
+/// point.
+#[derive(Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct MorphAnimation {
+    object_id: String,
+
+    curve: FunctionType,
+    ease: EaseType,
+
+    frame_range: Range,
+
+    morph: PathMorph,
+}
+
+impl MorphAnimation {
+    pub fn new<I: Into<String> + Clone>(
+        id: &I,
+        frames: impl Into<Range>,
+        paths: (&[PathLike], &[PathLike]),
+        curve: FunctionType,
+        ease: EaseType,
+    ) -> Self {
+        Self {
+            curve,
+            ease,
+            object_id: id.clone().into(),
+            frame_range: frames.into(),
+            morph: PathMorph::new(paths.0, paths.1),
+        }
+    }
+}
+
+impl Animation for MorphAnimation {
+    fn object_id(&self) -> &str {
+        &self.object_id
+    }
+
+    type OUTPUT = Vec<PathLike>;
+    fn get_value(&self, frame: usize) -> Self::OUTPUT {
+        let percentage = self.frame_range.percentage(frame);
+
+        self.morph
+            .interpolate(self.curve.delta(self.ease, percentage) as f64)
+    }
+
+    fn start_frame(&self) -> usize {
+        self.frame_range.start()
+    }
+
+    fn end_frame(&self) -> usize {
+        self.frame_range.end_bound()
+    }
+
+    fn resolve_time(&mut self, framerate: FPS) {
+        self.frame_range.resolve(framerate);
+    }
+}
@@ -0,0 +1,126 @@
+use std::cell::OnceCell;
+
+use rusvid_core::holder::likes::{PathLike, PathMeasure};
+use rusvid_core::point::Point;
+
+use super::{Animation, EaseType, FunctionType, Range};
+use crate::types::FPS;
+
+/// Moves an item along a path, the curve and the ease are applied to the distance on the path.
+///
+/// Like [`super::position_animation::PositionAnimation`] the first point of the item is set to the
+/// point on the path. With [`MotionPathAnimation::with_auto_orient`] the item is also rotated to
+/// follow the direction of the path, an item which points to the right at the rotation `0.0`
+/// points in the direction of the path.
+#[derive(Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct MotionPathAnimation {
+    object_id: String,
+
+    curve: FunctionType,
+    ease: EaseType,
+
+    frame_range: Range,
+
+    path: Vec<PathLike>,
+    auto_orient: bool,
+
+    /// The path is only measured once, after deserializing it's measured at the first use
+    #[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(skip))]
+    measure: OnceCell<PathMeasure>,
+}
+
+impl MotionPathAnimation {
+    pub fn new<I: Into<String> + Clone>(
+        id: &I,
+        frames: impl Into<Range>,
+        path: &[PathLike],
+        curve: FunctionType,
+        ease: EaseType,
+    ) -> Self {
+        Self {
+            curve,
+            ease,
+            object_id: id.clone().into(),
+            frame_range: frames.into(),
+            path: path.to_vec(),
+            auto_orient: false,
+            measure: OnceCell::from(PathMeasure::new(path)),
+        }
+    }
+
+    /// Rotates the item to follow the direction of the path, the rotation of the item is
+    /// overwritten.
+    pub fn with_auto_orient(mut self, auto_orient: bool) -> Self {
+        self.auto_orient = auto_orient;
+        self
+    }
+
+    pub fn auto_orient(&self) -> bool {
+        self.auto_orient
+    }
+
+    fn measure(&self) -> &PathMeasure {
+        self.measure.get_or_init(|| PathMeasure::new(&self.path))
+    }
+}
+
+impl Animation for MotionPathAnimation {
+    fn object_id(&self) -> &str {
+        &self.object_id
+    }
+
+    /// The position on the path and the rotation if the animation orients the item, the position
+    /// is `None` if the path has no segments.
+    type OUTPUT = (Option<Point>, Option<f64>);
+    fn get_value(&self, frame: usize) -> Self::OUTPUT {
+        let percentage = self.frame_range.percentage(frame);
+        let delta = self.curve.delta(self.ease, percentage) as f64;
+
+        let measure = self.measure();
+
+        let rotation = match self.auto_orient {
+            // the y axis points downwards and a positive angle rotates counterclockwise
+            true => measure
+                .tangent_at(delta)
+                .map(|tangent| -tangent.y().atan2(tangent.x())),
+            false => None,
+        };
+
+        (measure.point_at(delta), rotation)
+    }
+
+    fn start_frame(&self) -> usize {
+        self.frame_range.start()
+    }
+
+    fn end_frame(&self) -> usize {
+        self.frame_range.end_bound()
+    }
+
+    fn resolve_time(&mut self, framerate: FPS) {
+        self.frame_range.resolve(framerate);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measures_path_again_after_deserializing() {
+        let path = [
+            PathLike::Move(Point::ZERO),
+            PathLike::Line(Point::new(10.0, 0.0)),
+        ];
+        let mut animation =
+            MotionPathAnimation::new(&"id", 0..11, &path, FunctionType::Linear, EaseType::In);
+        let value = animation.get_value(5);
+
+        // like a deserialized animation without a measure
+        animation.measure = OnceCell::new();
+        assert_eq!(animation.get_value(5), value);
+        assert_eq!(value.0, Some(Point::new(5.0, 0.0)));
+    }
+}
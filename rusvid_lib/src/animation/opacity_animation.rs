@@ -0,0 +1,64 @@
+use super::{Animation, EaseType, FunctionType, Range};
+use crate::types::FPS;
+
+/// Fades the opacity of an item, a group or with the id of the object of a layer the whole layer.
+#[derive(Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct OpacityAnimation {
+    object_id: String,
+
+    curve: FunctionType,
+    ease: EaseType,
+
+    frame_range: Range,
+
+    start_opacity: f64,
+    end_opacity: f64,
+}
+
+impl OpacityAnimation {
+    pub fn new<I: Into<String> + Clone>(
+        id: &I,
+        frames: impl Into<Range>,
+        opacities: (f64, f64),
+        curve: FunctionType,
+        ease: EaseType,
+    ) -> Self {
+        Self {
+            curve,
+            ease,
+            object_id: id.clone().into(),
+            frame_range: frames.into(),
+            start_opacity: opacities.0,
+            end_opacity: opacities.1,
+        }
+    }
+}
+
+impl Animation for OpacityAnimation {
+    fn object_id(&self) -> &str {
+        &self.object_id
+    }
+
+    type OUTPUT = f64;
+    fn get_value(&self, frame: usize) -> Self::OUTPUT {
+        let percentage = self.frame_range.percentage(frame);
+
+        let delta = self.end_opacity - self.start_opacity;
+
+        self.start_opacity + delta * (self.curve.delta(self.ease, percentage) as f64)
+    }
+
+    fn start_frame(&self) -> usize {
+        self.frame_range.start()
+    }
+
+    fn end_frame(&self) -> usize {
+        self.frame_range.end_bound()
+    }
+
+    fn resolve_time(&mut self, framerate: FPS) {
+        self.frame_range.resolve(framerate);
+    }
+}
@@ -1,6 +1,7 @@
 use rusvid_core::prelude::Point;
 
 use super::{Animation, EaseType, FunctionType, Range};
+use crate::types::FPS;
 
 #[derive(Debug)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
@@ -57,4 +58,8 @@ impl Animation for PositionAnimation {
     fn end_frame(&self) -> usize {
         self.frame_range.end_bound()
     }
+
+    fn resolve_time(&mut self, framerate: FPS) {
+        self.frame_range.resolve(framerate);
+    }
 }
@@ -1,3 +1,16 @@
+use std::time::Duration;
+
+use crate::types::FPS;
+
+/// Framerate which is used for ranges in time until they are resolved with the framerate of the
+/// composition, it's the default framerate of [`crate::composition::CompositionBuilder`].
+pub(crate) const DEFAULT_FRAMERATE: FPS = 30;
+
+/// Frame at `time` with `framerate`, it's rounded to the nearest frame.
+pub(crate) fn frame_at(time: Duration, framerate: FPS) -> usize {
+    (time.as_secs_f64() * framerate as f64).round() as usize
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
@@ -17,11 +30,75 @@ pub struct Range {
     end: usize,
 
     typ: RangeType,
+
+    /// Start and end in time, the frames are calculated from it by [`Range::resolve`]
+    #[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(default))]
+    time: Option<(Duration, Duration)>,
 }
 
 impl Range {
     pub fn new(start: usize, end: usize, typ: RangeType) -> Self {
-        Range { start, end, typ }
+        Range {
+            start,
+            end,
+            typ,
+            time: None,
+        }
+    }
+
+    /// A range in time, the frames are calculated with the framerate of the composition when
+    /// the composition is updated. Until then a framerate of 30 fps is used.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use rusvid_lib::animation::{Range, RangeType};
+    ///
+    /// let mut range = Range::from_time(
+    ///     Duration::from_millis(500),
+    ///     Duration::from_secs(2),
+    ///     RangeType::Exclusive,
+    /// );
+    ///
+    /// range.resolve(60);
+    /// assert_eq!(range.start(), 30);
+    /// assert_eq!(range.end_bound(), 120);
+    ///
+    /// range.resolve(24);
+    /// assert_eq!(range.start(), 12);
+    /// assert_eq!(range.end_bound(), 48);
+    /// ```
+    pub fn from_time(start: Duration, end: Duration, typ: RangeType) -> Self {
+        let mut range = Range {
+            time: Some((start, end)),
+            ..Range::new(0, 0, typ)
+        };
+        range.resolve(DEFAULT_FRAMERATE);
+
+        range
+    }
+
+    /// Exclusive range from `start` to `end` in seconds, see [`Range::from_time`].
+    pub fn seconds(start: f64, end: f64) -> Self {
+        Range::from_time(
+            Duration::from_secs_f64(start),
+            Duration::from_secs_f64(end),
+            RangeType::Exclusive,
+        )
+    }
+
+    /// Start and end in time if the range isn't based on frames.
+    pub fn time(&self) -> Option<(Duration, Duration)> {
+        self.time
+    }
+
+    /// Calculates the frames of a range in time with `framerate`, the frames are rounded to the
+    /// nearest frame. Ranges based on frames aren't changed.
+    pub fn resolve(&mut self, framerate: FPS) {
+        if let Some((start, end)) = self.time {
+            self.start = frame_at(start, framerate);
+            self.end = frame_at(end, framerate);
+        }
     }
 
     pub fn as_std_range(&self) -> std::ops::Range<usize> {
@@ -66,7 +143,13 @@ impl Range {
         self.as_std_range().len()
     }
 
+    /// Progress of the `frame` between the first and the last frame of the range, a range with a
+    /// single frame or without frames is always finished.
     pub fn percentage(&self, frame: usize) -> f32 {
+        if self.len() <= 1 {
+            return 1.0;
+        }
+
         let frame_delta = (self.len() - 1) as f32;
         let current = (frame - self.start()) as f32;
 
@@ -86,6 +169,18 @@ impl From<std::ops::RangeInclusive<usize>> for Range {
     }
 }
 
+impl From<std::ops::Range<Duration>> for Range {
+    fn from(value: std::ops::Range<Duration>) -> Self {
+        Range::from_time(value.start, value.end, RangeType::Exclusive)
+    }
+}
+
+impl From<std::ops::RangeInclusive<Duration>> for Range {
+    fn from(value: std::ops::RangeInclusive<Duration>) -> Self {
+        Range::from_time(*value.start(), *value.end(), RangeType::Inclusive)
+    }
+}
+
 impl From<(usize, usize)> for Range {
     fn from(value: (usize, usize)) -> Self {
         (value.0..value.1).into()
@@ -133,4 +228,45 @@ mod tests {
         assert_eq!(0, Range::new(0, 100, RangeType::Exclusive).start());
         assert_eq!(101, Range::new(0, 101, RangeType::Exclusive).end_bound());
     }
+
+    #[test]
+    fn from_time() {
+        let mut range = Range::from(Duration::from_secs(1)..Duration::from_secs(2));
+        assert_eq!(range, Range::seconds(1.0, 2.0));
+        assert_eq!(range.as_std_range(), 30..60);
+
+        range.resolve(60);
+        assert_eq!(range.as_std_range(), 60..120);
+        assert_eq!(range.percentage(90), 30.0 / 59.0);
+
+        let mut inclusive = Range::from(Duration::ZERO..=Duration::from_millis(1500));
+        inclusive.resolve(10);
+        assert_eq!(inclusive.as_std_range(), 0..16);
+
+        // rounded to the nearest frame
+        let mut range = Range::seconds(0.03, 0.07);
+        range.resolve(25);
+        assert_eq!(range.as_std_range(), 1..2);
+        assert_eq!(range.percentage(1), 1.0);
+    }
+
+    #[test]
+    fn percentage() {
+        let range = Range::from(10..=20);
+        assert_eq!(range.percentage(10), 0.0);
+        assert_eq!(range.percentage(15), 0.5);
+        assert_eq!(range.percentage(20), 1.0);
+
+        assert_eq!(Range::from(10..=10).percentage(10), 1.0);
+        assert_eq!(Range::from(10..10).percentage(10), 1.0);
+    }
+
+    #[test]
+    fn frames_are_not_resolved() {
+        let mut range = Range::from(10..20);
+        range.resolve(60);
+
+        assert_eq!(range.as_std_range(), 10..20);
+        assert_eq!(range.time(), None);
+    }
 }
@@ -0,0 +1,83 @@
+use rusvid_core::point::Point;
+
+use super::{Animation, EaseType, FunctionType, Range};
+use crate::types::FPS;
+
+/// Rotates an item, the angles are in radiant and a positive angle rotates counterclockwise.
+///
+/// By default the item is rotated around the center of its path or the origin set with
+/// [`Transform::Origin`](rusvid_core::holder::transform::Transform::Origin), a pivot set with
+/// [`RotateAnimation::with_pivot`] replaces this origin.
+#[derive(Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct RotateAnimation {
+    object_id: String,
+
+    curve: FunctionType,
+    ease: EaseType,
+
+    frame_range: Range,
+
+    start_angle: f64,
+    end_angle: f64,
+
+    pivot: Option<Point>,
+}
+
+impl RotateAnimation {
+    pub fn new<I: Into<String> + Clone>(
+        id: &I,
+        frames: impl Into<Range>,
+        angles: (f64, f64),
+        curve: FunctionType,
+        ease: EaseType,
+    ) -> Self {
+        Self {
+            curve,
+            ease,
+            object_id: id.clone().into(),
+            frame_range: frames.into(),
+            start_angle: angles.0,
+            end_angle: angles.1,
+            pivot: None,
+        }
+    }
+
+    /// Rotates the item around `pivot`, the point is in the coordinates of the path.
+    pub fn with_pivot(mut self, pivot: Point) -> Self {
+        self.pivot = Some(pivot);
+        self
+    }
+
+    pub fn pivot(&self) -> Option<Point> {
+        self.pivot
+    }
+}
+
+impl Animation for RotateAnimation {
+    fn object_id(&self) -> &str {
+        &self.object_id
+    }
+
+    type OUTPUT = f64;
+    fn get_value(&self, frame: usize) -> Self::OUTPUT {
+        let percentage = self.frame_range.percentage(frame);
+
+        let delta = self.end_angle - self.start_angle;
+
+        self.start_angle + delta * (self.curve.delta(self.ease, percentage) as f64)
+    }
+
+    fn start_frame(&self) -> usize {
+        self.frame_range.start()
+    }
+
+    fn end_frame(&self) -> usize {
+        self.frame_range.end_bound()
+    }
+
+    fn resolve_time(&mut self, framerate: FPS) {
+        self.frame_range.resolve(framerate);
+    }
+}
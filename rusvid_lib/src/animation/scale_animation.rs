@@ -0,0 +1,69 @@
+use rusvid_core::point::Point;
+
+use super::{Animation, EaseType, FunctionType, Range};
+use crate::types::FPS;
+
+/// Scales an item along the x and y axis, `Point::ONE` is the original size.
+///
+/// The item is scaled around the center of its path or the origin set with
+/// [`Transform::Origin`](rusvid_core::holder::transform::Transform::Origin).
+#[derive(Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct ScaleAnimation {
+    object_id: String,
+
+    curve: FunctionType,
+    ease: EaseType,
+
+    frame_range: Range,
+
+    start_scale: Point,
+    end_scale: Point,
+}
+
+impl ScaleAnimation {
+    pub fn new<I: Into<String> + Clone>(
+        id: &I,
+        frames: impl Into<Range>,
+        scales: (Point, Point),
+        curve: FunctionType,
+        ease: EaseType,
+    ) -> Self {
+        Self {
+            curve,
+            ease,
+            object_id: id.clone().into(),
+            frame_range: frames.into(),
+            start_scale: scales.0,
+            end_scale: scales.1,
+        }
+    }
+}
+
+impl Animation for ScaleAnimation {
+    fn object_id(&self) -> &str {
+        &self.object_id
+    }
+
+    type OUTPUT = Point;
+    fn get_value(&self, frame: usize) -> Self::OUTPUT {
+        let percentage = self.frame_range.percentage(frame);
+
+        let delta = self.end_scale - self.start_scale;
+
+        self.start_scale + delta * (self.curve.delta(self.ease, percentage) as f64)
+    }
+
+    fn start_frame(&self) -> usize {
+        self.frame_range.start()
+    }
+
+    fn end_frame(&self) -> usize {
+        self.frame_range.end_bound()
+    }
+
+    fn resolve_time(&mut self, framerate: FPS) {
+        self.frame_range.resolve(framerate);
+    }
+}
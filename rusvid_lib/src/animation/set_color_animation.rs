@@ -1,6 +1,10 @@
+use std::time::Duration;
+
 use rusvid_core::holder::likes::ColorLike;
 
+use super::range::{frame_at, DEFAULT_FRAMERATE};
 use super::Animation;
+use crate::types::FPS;
 
 #[derive(Debug)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
@@ -11,6 +15,10 @@ pub struct SetColorAnimation {
     frame: usize,
 
     color_like: Option<ColorLike>,
+
+    /// Time of the change, the frame is calculated from it by [`Animation::resolve_time`]
+    #[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(default))]
+    time: Option<Duration>,
 }
 
 impl SetColorAnimation {
@@ -19,8 +27,27 @@ impl SetColorAnimation {
             object_id: id.clone().into(),
             frame,
             color_like: color,
+            time: None,
+        }
+    }
+
+    /// Sets the color at `time`, the frame is calculated with the framerate of the composition
+    /// when the composition is updated, see [`super::Range::from_time`].
+    pub fn at_time<I: Into<String> + Clone>(
+        id: &I,
+        time: Duration,
+        color: Option<ColorLike>,
+    ) -> Self {
+        Self {
+            time: Some(time),
+            ..SetColorAnimation::new(id, frame_at(time, DEFAULT_FRAMERATE), color)
         }
     }
+
+    /// Time of the change if it isn't based on a frame.
+    pub fn time(&self) -> Option<Duration> {
+        self.time
+    }
 }
 
 impl Animation for SetColorAnimation {
@@ -40,4 +67,10 @@ impl Animation for SetColorAnimation {
     fn end_frame(&self) -> usize {
         self.frame
     }
+
+    fn resolve_time(&mut self, framerate: FPS) {
+        if let Some(time) = self.time {
+            self.frame = frame_at(time, framerate);
+        }
+    }
 }
@@ -0,0 +1,83 @@
+use rusvid_core::holder::stroke::Stroke;
+
+use super::{Animation, EaseType, FunctionType, Range};
+use crate::types::FPS;
+
+/// Interpolates the width and the dash offset of a stroke, all other values are taken from the
+/// `stroke`.
+///
+/// Animating the dash offset of a dashed stroke creates "marching ants", animating the dash
+/// offset from the length of the path to `0.0` with the dash array `[length, length]` draws the
+/// line.
+#[derive(Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct StrokeAnimation {
+    object_id: String,
+
+    curve: FunctionType,
+    ease: EaseType,
+
+    frame_range: Range,
+
+    stroke: Stroke,
+    start_width: f64,
+    end_width: f64,
+    start_dashoffset: f64,
+    end_dashoffset: f64,
+}
+
+impl StrokeAnimation {
+    pub fn new<I: Into<String> + Clone>(
+        id: &I,
+        frames: impl Into<Range>,
+        stroke: Stroke,
+        widths: (f64, f64),
+        dashoffsets: (f64, f64),
+        curve: FunctionType,
+        ease: EaseType,
+    ) -> Self {
+        Self {
+            curve,
+            ease,
+            object_id: id.clone().into(),
+            frame_range: frames.into(),
+            stroke,
+            start_width: widths.0,
+            end_width: widths.1,
+            start_dashoffset: dashoffsets.0,
+            end_dashoffset: dashoffsets.1,
+        }
+    }
+}
+
+impl Animation for StrokeAnimation {
+    fn object_id(&self) -> &str {
+        &self.object_id
+    }
+
+    type OUTPUT = Stroke;
+    fn get_value(&self, frame: usize) -> Self::OUTPUT {
+        let percentage = self.frame_range.percentage(frame);
+        let delta = self.curve.delta(self.ease, percentage) as f64;
+
+        Stroke {
+            width: self.start_width + (self.end_width - self.start_width) * delta,
+            dashoffset: self.start_dashoffset
+                + (self.end_dashoffset - self.start_dashoffset) * delta,
+            ..self.stroke.clone()
+        }
+    }
+
+    fn start_frame(&self) -> usize {
+        self.frame_range.start()
+    }
+
+    fn end_frame(&self) -> usize {
+        self.frame_range.end_bound()
+    }
+
+    fn resolve_time(&mut self, framerate: FPS) {
+        self.frame_range.resolve(framerate);
+    }
+}
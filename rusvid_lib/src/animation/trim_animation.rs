@@ -0,0 +1,68 @@
+use super::{Animation, EaseType, FunctionType, Range};
+use crate::types::FPS;
+
+/// Animates the start and the end of the visible part of a path, both are percentages from `0.0`
+/// to `1.0` of the length of the path. Animating the end from `0.0` to `1.0` draws the path on.
+#[derive(Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct TrimAnimation {
+    object_id: String,
+
+    curve: FunctionType,
+    ease: EaseType,
+
+    frame_range: Range,
+
+    start_trim: (f64, f64),
+    end_trim: (f64, f64),
+}
+
+impl TrimAnimation {
+    /// `trims` are the start and the end of the visible part at the first and at the last frame.
+    pub fn new<I: Into<String> + Clone>(
+        id: &I,
+        frames: impl Into<Range>,
+        trims: ((f64, f64), (f64, f64)),
+        curve: FunctionType,
+        ease: EaseType,
+    ) -> Self {
+        Self {
+            curve,
+            ease,
+            object_id: id.clone().into(),
+            frame_range: frames.into(),
+            start_trim: trims.0,
+            end_trim: trims.1,
+        }
+    }
+}
+
+impl Animation for TrimAnimation {
+    fn object_id(&self) -> &str {
+        &self.object_id
+    }
+
+    type OUTPUT = (f64, f64);
+    fn get_value(&self, frame: usize) -> Self::OUTPUT {
+        let percentage = self.frame_range.percentage(frame);
+        let delta = self.curve.delta(self.ease, percentage) as f64;
+
+        (
+            self.start_trim.0 + (self.end_trim.0 - self.start_trim.0) * delta,
+            self.start_trim.1 + (self.end_trim.1 - self.start_trim.1) * delta,
+        )
+    }
+
+    fn start_frame(&self) -> usize {
+        self.frame_range.start()
+    }
+
+    fn end_frame(&self) -> usize {
+        self.frame_range.end_bound()
+    }
+
+    fn resolve_time(&mut self, framerate: FPS) {
+        self.frame_range.resolve(framerate);
+    }
+}
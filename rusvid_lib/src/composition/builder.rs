@@ -1,3 +1,5 @@
+use rusvid_core::working_plane::ColorSpace;
+
 use crate::composition::Composition;
 use crate::effect::EffectLogic;
 use crate::layer::Layer;
@@ -11,6 +13,7 @@ pub struct CompositionBuilder {
     duration: u16,
     name: String,
     layers: Vec<Layer>,
+    color_space: ColorSpace,
     effects: Vec<Box<dyn EffectLogic>>,
 }
 
@@ -24,6 +27,7 @@ impl Default for CompositionBuilder {
             duration: 10,
             name: "UNKNOWN".to_string(),
             layers: Vec::new(),
+            color_space: ColorSpace::default(),
             effects: Vec::new(),
         }
     }
@@ -37,6 +41,8 @@ impl CompositionBuilder {
             duration: self.duration,
             name: self.name,
             layers: self.layers,
+            color_space: self.color_space,
+            frame: 0,
             effects: self.effects,
         }
     }
@@ -61,6 +67,12 @@ impl CompositionBuilder {
         self
     }
 
+    /// Sets the color space in which the layers are combined, see [`ColorSpace`].
+    pub fn color_space(mut self, color_space: ColorSpace) -> Self {
+        self.color_space = color_space;
+        self
+    }
+
     pub fn add_layer(mut self, layer: Layer) -> Self {
         self.layers.push(layer);
         self
@@ -1,4 +1,6 @@
 use anyhow::Result;
+use rusvid_core::holder::utils;
+use rusvid_core::working_plane::ColorSpace;
 use tracing::debug;
 
 use crate::composition::CompositionBuilder;
@@ -26,6 +28,14 @@ pub struct Composition {
 
     pub layers: Vec<Layer>,
 
+    /// The color space in which the layers are combined
+    #[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(default))]
+    pub(crate) color_space: ColorSpace,
+
+    /// The frame of the last update
+    #[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(skip))]
+    pub(crate) frame: usize,
+
     // TODO remove serde skip
     #[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(skip))]
     pub(crate) effects: Vec<Box<dyn EffectLogic>>,
@@ -43,17 +53,52 @@ impl Composition {
         self.resolution
     }
 
+    pub fn color_space(&self) -> ColorSpace {
+        self.color_space
+    }
+
+    /// The frame of the last [`Composition::update`], it decides which layers are active.
+    pub fn frame(&self) -> usize {
+        self.frame
+    }
+
     pub fn update(&mut self, frame_count: usize) -> Result<()> {
         debug!("Update composition at frame: {frame_count}");
+        self.frame = frame_count;
 
+        let framerate = self.framerate;
         for layer in self.get_layers_mut() {
+            layer.resolve_time(framerate);
             layer.update(frame_count)?;
         }
 
         Ok(())
     }
 
-    // TODO add layer
+    /// Writes the current frame of the composition as svg markup.
+    ///
+    /// Every active layer is written as group with the id of the layer's object, the layers are in
+    /// the same order as they are rendered. Effects only work on pixels and are therefore not applied.
+    /// Call [`Composition::update`] beforehand to export a specific frame.
+    pub fn to_svg_string(&self) -> Result<String> {
+        let resolution = self.resolution();
+        let tree = utils::new_resvg_tree(resolution.width(), resolution.height())?;
+
+        for layer in self.get_layers() {
+            if !layer.is_active(self.frame) {
+                continue;
+            }
+
+            layer.object.append_to_resvg_node(&tree.root)?;
+        }
+
+        Ok(utils::resvg_tree_to_svg_string(&tree))
+    }
+
+    pub fn add_layer(&mut self, layer: Layer) {
+        self.layers.push(layer);
+    }
+
     // TODO get layer by id (only if they have an id)
 
     pub fn get_layers(&self) -> &Vec<Layer> {
@@ -0,0 +1,281 @@
+use rusvid_core::working_plane::WorkingPixel;
+
+type Rgb = [f64; 3];
+
+/// How the colors of a layer are mixed with the colors of the layers beneath it.
+///
+/// The formulas are from the specification
+/// [Compositing and Blending](https://www.w3.org/TR/compositing-1/#blending), `Add` is the
+/// clamped sum of both colors.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub enum BlendMode {
+    /// The layer is placed over the layers beneath it
+    #[default]
+    Normal,
+    Multiply,
+    Screen,
+    Overlay,
+    Darken,
+    Lighten,
+    ColorDodge,
+    ColorBurn,
+    HardLight,
+    SoftLight,
+    Difference,
+    Exclusion,
+    Add,
+    /// Hue of the layer with the saturation and luminosity beneath it
+    Hue,
+    /// Saturation of the layer with the hue and luminosity beneath it
+    Saturation,
+    /// Hue and saturation of the layer with the luminosity beneath it
+    Color,
+    /// Luminosity of the layer with the hue and saturation beneath it
+    Luminosity,
+}
+
+impl BlendMode {
+    /// Mixes the color of the layer `source` with the color beneath it `backdrop`, the channels
+    /// are from `0.0` to `1.0` and without alpha.
+    pub fn blend(&self, backdrop: Rgb, source: Rgb) -> Rgb {
+        let separable = |f: fn(f64, f64) -> f64| {
+            [
+                f(backdrop[0], source[0]),
+                f(backdrop[1], source[1]),
+                f(backdrop[2], source[2]),
+            ]
+        };
+
+        match self {
+            BlendMode::Normal => source,
+            BlendMode::Multiply => separable(multiply),
+            BlendMode::Screen => separable(screen),
+            BlendMode::Overlay => separable(|b, s| hard_light(s, b)),
+            BlendMode::Darken => separable(f64::min),
+            BlendMode::Lighten => separable(f64::max),
+            BlendMode::ColorDodge => separable(color_dodge),
+            BlendMode::ColorBurn => separable(color_burn),
+            BlendMode::HardLight => separable(hard_light),
+            BlendMode::SoftLight => separable(soft_light),
+            BlendMode::Difference => separable(|b, s| (b - s).abs()),
+            BlendMode::Exclusion => separable(|b, s| b + s - 2.0 * b * s),
+            BlendMode::Add => separable(|b, s| (b + s).min(1.0)),
+            BlendMode::Hue => set_lum(set_sat(source, sat(backdrop)), lum(backdrop)),
+            BlendMode::Saturation => set_lum(set_sat(backdrop, sat(source)), lum(backdrop)),
+            BlendMode::Color => set_lum(source, lum(backdrop)),
+            BlendMode::Luminosity => set_lum(backdrop, lum(source)),
+        }
+    }
+
+    /// Places `source` over `backdrop`, where both pixels overlap the colors are mixed with
+    /// [`BlendMode::blend`].
+    pub fn composite(&self, backdrop: &WorkingPixel, source: &WorkingPixel) -> WorkingPixel {
+        if *self == BlendMode::Normal {
+            return source.over(backdrop);
+        }
+
+        let to_rgb = |color: [f32; 3]| color.map(|channel| channel as f64);
+
+        let (bg_a, fg_a) = (backdrop.alpha(), source.alpha());
+        let blended = self.blend(to_rgb(backdrop.color()), to_rgb(source.color()));
+
+        // the blended color replaces the color of the layer where both pixels overlap
+        let (bg, fg) = (backdrop.premultiplied(), source.premultiplied());
+        let channel = |i: usize| {
+            fg[i] * (1.0 - bg_a) + bg[i] * (1.0 - fg_a) + fg_a * bg_a * blended[i] as f32
+        };
+
+        WorkingPixel::new_premultiplied([
+            channel(0),
+            channel(1),
+            channel(2),
+            fg_a + bg_a * (1.0 - fg_a),
+        ])
+    }
+}
+
+fn multiply(b: f64, s: f64) -> f64 {
+    b * s
+}
+
+fn screen(b: f64, s: f64) -> f64 {
+    b + s - b * s
+}
+
+fn hard_light(b: f64, s: f64) -> f64 {
+    match s <= 0.5 {
+        true => multiply(b, 2.0 * s),
+        false => screen(b, 2.0 * s - 1.0),
+    }
+}
+
+fn color_dodge(b: f64, s: f64) -> f64 {
+    match (b, s) {
+        (b, _) if b <= 0.0 => 0.0,
+        (_, s) if s >= 1.0 => 1.0,
+        (b, s) => (b / (1.0 - s)).min(1.0),
+    }
+}
+
+fn color_burn(b: f64, s: f64) -> f64 {
+    match (b, s) {
+        (b, _) if b >= 1.0 => 1.0,
+        (_, s) if s <= 0.0 => 0.0,
+        (b, s) => 1.0 - ((1.0 - b) / s).min(1.0),
+    }
+}
+
+fn soft_light(b: f64, s: f64) -> f64 {
+    if s <= 0.5 {
+        return b - (1.0 - 2.0 * s) * b * (1.0 - b);
+    }
+
+    let d = match b <= 0.25 {
+        true => ((16.0 * b - 12.0) * b + 4.0) * b,
+        false => b.sqrt(),
+    };
+
+    b + (2.0 * s - 1.0) * (d - b)
+}
+
+fn lum(c: Rgb) -> f64 {
+    0.3 * c[0] + 0.59 * c[1] + 0.11 * c[2]
+}
+
+fn clip_color(c: Rgb) -> Rgb {
+    let l = lum(c);
+    let n = c[0].min(c[1]).min(c[2]);
+    let x = c[0].max(c[1]).max(c[2]);
+
+    c.map(|channel| match (n < 0.0, x > 1.0) {
+        (true, _) => l + (channel - l) * l / (l - n),
+        (_, true) => l + (channel - l) * (1.0 - l) / (x - l),
+        _ => channel,
+    })
+}
+
+fn set_lum(c: Rgb, l: f64) -> Rgb {
+    let d = l - lum(c);
+
+    clip_color(c.map(|channel| channel + d))
+}
+
+fn sat(c: Rgb) -> f64 {
+    c[0].max(c[1]).max(c[2]) - c[0].min(c[1]).min(c[2])
+}
+
+fn set_sat(c: Rgb, s: f64) -> Rgb {
+    let mut order = [0, 1, 2];
+    order.sort_by(|a, b| c[*a].total_cmp(&c[*b]));
+    let [min, mid, max] = order;
+
+    let mut result = [0.0; 3];
+    if c[max] > c[min] {
+        result[mid] = (c[mid] - c[min]) * s / (c[max] - c[min]);
+        result[max] = s;
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+    use rusvid_core::pixel::Pixel;
+    use rusvid_core::working_plane::ColorSpace;
+
+    use super::*;
+
+    const GRAY: Rgb = [0.5, 0.5, 0.5];
+    const ORANGE: Rgb = [1.0, 0.5, 0.0];
+
+    fn assert_rgb(a: Rgb, b: Rgb) {
+        for i in 0..3 {
+            assert_abs_diff_eq!(a[i], b[i], epsilon = 0.0001);
+        }
+    }
+
+    #[test]
+    fn separable() {
+        assert_rgb(BlendMode::Normal.blend(GRAY, ORANGE), ORANGE);
+        assert_rgb(BlendMode::Multiply.blend(GRAY, ORANGE), [0.5, 0.25, 0.0]);
+        assert_rgb(BlendMode::Screen.blend(GRAY, ORANGE), [1.0, 0.75, 0.5]);
+        assert_rgb(BlendMode::Darken.blend(GRAY, ORANGE), [0.5, 0.5, 0.0]);
+        assert_rgb(BlendMode::Lighten.blend(GRAY, ORANGE), [1.0, 0.5, 0.5]);
+        assert_rgb(BlendMode::Difference.blend(GRAY, ORANGE), [0.5, 0.0, 0.5]);
+        assert_rgb(BlendMode::Add.blend(GRAY, ORANGE), [1.0, 1.0, 0.5]);
+        assert_rgb(BlendMode::Exclusion.blend(GRAY, ORANGE), [0.5, 0.5, 0.5]);
+
+        // overlay is hard light with swapped layers
+        assert_rgb(
+            BlendMode::Overlay.blend([0.2, 0.8, 0.5], [0.7, 0.1, 0.4]),
+            BlendMode::HardLight.blend([0.7, 0.1, 0.4], [0.2, 0.8, 0.5]),
+        );
+        assert_rgb(
+            BlendMode::Overlay.blend([0.2, 0.8, 0.5], GRAY),
+            [0.2, 0.8, 0.5],
+        );
+    }
+
+    #[test]
+    fn non_separable() {
+        // a gray backdrop has no hue and no saturation
+        assert_rgb(BlendMode::Saturation.blend(GRAY, ORANGE), GRAY);
+        assert_rgb(BlendMode::Hue.blend(GRAY, ORANGE), GRAY);
+
+        let color = BlendMode::Color.blend(GRAY, ORANGE);
+        assert_abs_diff_eq!(lum(color), 0.5, epsilon = 0.0001);
+        assert!(color[0] > color[1] && color[1] > color[2]);
+
+        let luminosity = BlendMode::Luminosity.blend(ORANGE, GRAY);
+        assert_abs_diff_eq!(lum(luminosity), 0.5, epsilon = 0.0001);
+        assert!(luminosity[0] > luminosity[1] && luminosity[1] > luminosity[2]);
+    }
+
+    #[test]
+    fn composite() {
+        let pixel = |r: u8, g: u8, b: u8, a: u8| {
+            WorkingPixel::from_pixel(&Pixel::new(r, g, b, a), ColorSpace::Srgb)
+        };
+        let composite = |blend_mode: BlendMode, backdrop: WorkingPixel, source: WorkingPixel| {
+            blend_mode
+                .composite(&backdrop, &source)
+                .to_pixel(ColorSpace::Srgb)
+        };
+
+        let red = pixel(255, 0, 0, 255);
+        let green = pixel(0, 255, 0, 255);
+        let gray = pixel(128, 128, 128, 255);
+
+        assert_eq!(
+            composite(BlendMode::Normal, red, green),
+            Pixel::new(0, 255, 0, 255)
+        );
+        assert_eq!(
+            composite(BlendMode::Add, red, green),
+            Pixel::new(255, 255, 0, 255)
+        );
+        assert_eq!(
+            composite(BlendMode::Multiply, gray, pixel(255, 255, 255, 255)),
+            Pixel::new(128, 128, 128, 255)
+        );
+
+        // without a backdrop the layer keeps its own color
+        assert_eq!(
+            composite(BlendMode::Multiply, WorkingPixel::ZERO, green),
+            Pixel::new(0, 255, 0, 255)
+        );
+
+        // half of the mixed color
+        assert_eq!(
+            composite(
+                BlendMode::Multiply,
+                pixel(255, 255, 255, 255),
+                pixel(0, 255, 0, 128)
+            ),
+            Pixel::new(127, 255, 127, 255)
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use anyhow::{bail, Result};
+use rusvid_core::holder::likes::TypesLike;
+use rusvid_core::holder::object::Object;
+use rusvid_core::holder::svg_holder::SvgHolder;
+use rusvid_core::holder::svg_item::SvgItem;
+use rusvid_core::pixel::Pixel;
+use rusvid_core::plane::{Plane, SIZE};
+
+use crate::layer::Layer;
+
+/// Which value of the matte is used as visibility of the masked layer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub enum MatteMode {
+    /// The layer is visible where the matte is opaque
+    #[default]
+    Alpha,
+    /// The layer is visible where the matte is transparent
+    AlphaInverted,
+    /// The layer is visible where the matte is bright
+    Luma,
+    /// The layer is visible where the matte is dark
+    LumaInverted,
+}
+
+impl MatteMode {
+    /// Visibility from `0.0` to `1.0` of a masked pixel, a transparent pixel has no luminance.
+    pub fn coverage(&self, matte: &Pixel) -> f32 {
+        let alpha = matte[3] as f32 / 255.0;
+        let luma = || {
+            (0.2126 * matte[0] as f32 + 0.7152 * matte[1] as f32 + 0.0722 * matte[2] as f32) / 255.0
+                * alpha
+        };
+
+        match self {
+            MatteMode::Alpha => alpha,
+            MatteMode::AlphaInverted => 1.0 - alpha,
+            MatteMode::Luma => luma(),
+            MatteMode::LumaInverted => 1.0 - luma(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub enum MaskSource {
+    /// Another layer of the composition referenced by the id of its object, like a track matte
+    /// the layer itself isn't visible
+    Layer(String),
+    /// The filled path of the item
+    Path(Box<SvgItem>),
+}
+
+/// Hides parts of a layer by the alpha or the luminance of a matte, see [`Layer::set_mask`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct Mask {
+    source: MaskSource,
+    mode: MatteMode,
+}
+
+impl Mask {
+    pub fn new(source: MaskSource, mode: MatteMode) -> Self {
+        Mask { source, mode }
+    }
+
+    /// Uses the layer with the object id `id` as track matte.
+    pub fn layer(id: impl Into<String>, mode: MatteMode) -> Self {
+        Mask::new(MaskSource::Layer(id.into()), mode)
+    }
+
+    /// Clips the layer to the path of `item`.
+    pub fn path(item: SvgItem, mode: MatteMode) -> Self {
+        Mask::new(MaskSource::Path(Box::new(item)), mode)
+    }
+
+    pub fn source(&self) -> &MaskSource {
+        &self.source
+    }
+
+    pub fn mode(&self) -> MatteMode {
+        self.mode
+    }
+
+    /// The id of the matte layer if the mask uses a layer.
+    pub fn layer_id(&self) -> Option<&str> {
+        match &self.source {
+            MaskSource::Layer(id) => Some(id),
+            MaskSource::Path(_) => None,
+        }
+    }
+
+    /// Visibility from `0.0` to `1.0` for every pixel, `planes` are the rendered `layers` and
+    /// `None` for inactive layers. An inactive matte is fully transparent.
+    pub(crate) fn coverage(
+        &self,
+        layers: &[Layer],
+        planes: &[Option<Plane>],
+        width: SIZE,
+        height: SIZE,
+    ) -> Result<Vec<f32>> {
+        let rendered;
+        let matte = match &self.source {
+            MaskSource::Layer(id) => {
+                match layers.iter().position(|layer| layer.object.id() == id) {
+                    Some(index) => match &planes[index] {
+                        Some(plane) => plane,
+                        None => {
+                            let coverage = self.mode.coverage(&Pixel::ZERO);
+                            return Ok(vec![coverage; (width * height) as usize]);
+                        }
+                    },
+                    None => bail!("No layer with the id `{id}` as matte"),
+                }
+            }
+            MaskSource::Path(item) => {
+                let holder = SvgHolder::new_with_items(vec![item.as_ref().clone()]);
+                rendered = Object::new(TypesLike::Svg(holder)).render(width, height)?;
+
+                &rendered
+            }
+        };
+
+        Ok(matte
+            .as_data()
+            .iter()
+            .map(|pixel| self.mode.coverage(pixel))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+
+    #[test]
+    fn coverage() {
+        let half_white = Pixel::new(255, 255, 255, 128);
+        let black = Pixel::new(0, 0, 0, 255);
+
+        assert_abs_diff_eq!(MatteMode::Alpha.coverage(&half_white), 128.0 / 255.0);
+        assert_abs_diff_eq!(MatteMode::AlphaInverted.coverage(&black), 0.0);
+        assert_abs_diff_eq!(
+            MatteMode::Luma.coverage(&half_white),
+            128.0 / 255.0,
+            epsilon = 0.0001
+        );
+        assert_abs_diff_eq!(MatteMode::Luma.coverage(&black), 0.0);
+        assert_abs_diff_eq!(MatteMode::LumaInverted.coverage(&black), 1.0);
+        assert_abs_diff_eq!(MatteMode::LumaInverted.coverage(&Pixel::ZERO), 1.0);
+    }
+}
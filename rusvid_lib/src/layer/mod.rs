@@ -1,3 +1,11 @@
+mod blend_mode;
+mod mask;
+mod precomp;
 mod strukt;
+mod timing;
 
+pub use blend_mode::BlendMode;
+pub use mask::{Mask, MaskSource, MatteMode};
+pub use precomp::Precomp;
 pub use strukt::{Layer, LayerType};
+pub use timing::Timing;
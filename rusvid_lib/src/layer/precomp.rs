@@ -0,0 +1,143 @@
+use anyhow::Result;
+use rusvid_core::plane::Plane;
+
+use crate::composition::Composition;
+use crate::metrics::MetricsVideo;
+use crate::renderer::render_composition;
+use crate::types::FPS;
+
+/// A composition nested as layer into another composition, e.g. a reusable lower third.
+///
+/// The nested composition keeps its own resolution and framerate, the frames of the parent are
+/// mapped onto it by time. Use the [`crate::layer::Timing`] of the layer to shift or speed up the
+/// nested composition.
+#[derive(Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct Precomp {
+    composition: Box<Composition>,
+
+    /// Starts again from the beginning after the last frame instead of disappearing
+    looping: bool,
+
+    /// Framerate of the parent composition, it's set by [`Precomp::resolve_time`]
+    framerate: FPS,
+}
+
+impl Precomp {
+    pub fn new(composition: Composition) -> Self {
+        Precomp {
+            framerate: composition.framerate,
+            composition: Box::new(composition),
+            looping: false,
+        }
+    }
+
+    pub fn with_looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
+    pub fn composition(&self) -> &Composition {
+        &self.composition
+    }
+
+    pub fn composition_mut(&mut self) -> &mut Composition {
+        &mut self.composition
+    }
+
+    pub fn looping(&self) -> bool {
+        self.looping
+    }
+
+    pub fn set_looping(&mut self, looping: bool) {
+        self.looping = looping;
+    }
+
+    /// Sets the framerate of the parent composition.
+    pub fn resolve_time(&mut self, framerate: FPS) {
+        self.framerate = framerate;
+    }
+
+    /// Frame of the nested composition at the `frame` of the parent, `None` after the last frame
+    /// of a composition which doesn't loop.
+    pub fn frame_at(&self, frame: usize) -> Option<usize> {
+        let frames = self.composition.frames();
+        if frames == 0 {
+            return None;
+        }
+
+        let nested = frame * self.composition.framerate as usize / self.framerate.max(1) as usize;
+
+        match (self.looping, nested < frames) {
+            (true, _) => Some(nested % frames),
+            (false, true) => Some(nested),
+            (false, false) => None,
+        }
+    }
+
+    /// Updates and renders the nested composition at the `frame` of the parent, without a frame
+    /// or without layers the nested composition is transparent.
+    pub fn render(&mut self, frame: Option<usize>) -> Result<Plane> {
+        let resolution = self.composition.resolution();
+
+        match frame.and_then(|frame| self.frame_at(frame)) {
+            Some(nested) if !self.composition.get_layers().is_empty() => {
+                self.composition.update(nested)?;
+                render_composition(&self.composition)
+            }
+            _ => Ok(Plane::new(resolution.width(), resolution.height())?),
+        }
+    }
+}
+
+impl From<Composition> for Precomp {
+    fn from(value: Composition) -> Self {
+        Precomp::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn precomp(framerate: FPS, duration: u16) -> Precomp {
+        Precomp::new(
+            Composition::builder()
+                .framerate(framerate)
+                .duration(duration)
+                .build(),
+        )
+    }
+
+    #[test]
+    fn same_framerate() {
+        let precomp = precomp(10, 1);
+
+        assert_eq!(precomp.frame_at(0), Some(0));
+        assert_eq!(precomp.frame_at(9), Some(9));
+        assert_eq!(precomp.frame_at(10), None);
+    }
+
+    #[test]
+    fn maps_frames_by_time() {
+        let mut precomp = precomp(10, 1);
+        precomp.resolve_time(20);
+
+        assert_eq!(precomp.frame_at(1), Some(0));
+        assert_eq!(precomp.frame_at(2), Some(1));
+        assert_eq!(precomp.frame_at(19), Some(9));
+        assert_eq!(precomp.frame_at(20), None);
+
+        precomp.resolve_time(5);
+        assert_eq!(precomp.frame_at(1), Some(2));
+    }
+
+    #[test]
+    fn looping() {
+        let precomp = precomp(10, 1).with_looping(true);
+
+        assert_eq!(precomp.frame_at(10), Some(0));
+        assert_eq!(precomp.frame_at(25), Some(5));
+    }
+}
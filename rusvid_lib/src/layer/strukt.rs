@@ -1,21 +1,33 @@
+use std::path::Path;
+
 use anyhow::{bail, Result};
+use rusvid_core::holder::image_holder::ImageHolder;
 use rusvid_core::holder::likes::TypesLike;
 use rusvid_core::holder::object::Object;
 use rusvid_core::holder::svg_holder::SvgHolder;
 use rusvid_core::holder::svg_item::SvgItem;
+use rusvid_core::holder::text_holder::TextHolder;
 use rusvid_core::holder::transform::{Transform, TransformLogic};
 use rusvid_core::holder::utils::random_id;
+use rusvid_core::plane::Plane;
+use rusvid_core::point::Point;
 use rusvid_effect::EffectLogic;
 use static_assertions::const_assert_eq;
 
 use crate::animation::position_animation::PositionAnimation;
 use crate::animation::set_color_animation::SetColorAnimation;
-use crate::animation::{Animation, AnimationType};
+use crate::animation::{Animation, AnimationType, Range};
+use crate::composition::Composition;
+use crate::layer::{BlendMode, Mask, Precomp, Timing};
 use crate::resolution::Resolution;
+use crate::types::FPS;
 
 pub enum LayerType {
     Svg,
     Image,
+    Text,
+    /// An empty nested composition with the resolution of the parent, see [`Layer::precomp_mut`]
+    Composition,
 }
 
 // These two enums must ALWAYS be in synch
@@ -35,25 +47,135 @@ pub struct Layer {
 
     animations: Vec<AnimationType>,
 
+    #[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(default))]
+    blend_mode: BlendMode,
+
+    #[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(default))]
+    mask: Option<Mask>,
+
+    #[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(default))]
+    timing: Timing,
+
+    #[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(default))]
+    precomp: Option<Precomp>,
+
     #[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(skip))]
     pub(crate) effects: Vec<Box<dyn EffectLogic>>,
 }
 
 impl Layer {
-    pub fn new(layer_type: LayerType, _resolution: Resolution) -> Self {
+    /// Creates an empty layer, an image layer starts with a transparent image of the size of the
+    /// `resolution`.
+    pub fn new(layer_type: LayerType, resolution: Resolution) -> Self {
+        let precomp = match layer_type {
+            LayerType::Composition => Some(Precomp::new(
+                Composition::builder().resolution(resolution).build(),
+            )),
+            _ => None,
+        };
+
         Self {
             _name: format!("layer_{}", random_id()),
             object: Object::new(match layer_type {
                 LayerType::Svg => TypesLike::Svg(SvgHolder::new()),
-                LayerType::Image => todo!(),
+                LayerType::Image => TypesLike::Image(ImageHolder::new_fit(
+                    Point::ZERO,
+                    Plane::new(resolution.width(), resolution.height())
+                        .expect("resolution is greater than zero"),
+                )),
+                LayerType::Text => TypesLike::Text(TextHolder::default()),
+                LayerType::Composition => TypesLike::Composition(ImageHolder::new_fit(
+                    Point::ZERO,
+                    Plane::new(resolution.width(), resolution.height())
+                        .expect("resolution is greater than zero"),
+                )),
             }),
 
             animations: Vec::new(),
+            blend_mode: BlendMode::default(),
+            mask: None,
+            timing: Timing::default(),
+            precomp,
             effects: Vec::new(),
         }
     }
 
+    /// Creates a svg layer from all paths of the svg file, the svg is fitted into the `resolution`.
+    ///
+    /// For more info see [`SvgHolder::from_file_with_size`].
+    pub fn from_file(resolution: Resolution, path: impl AsRef<Path>) -> Result<Self> {
+        let svg_holder = SvgHolder::from_file_with_size(
+            path,
+            resolution.width() as f64,
+            resolution.height() as f64,
+        )?;
+
+        let mut layer = Layer::new(LayerType::Svg, resolution);
+        layer.object = Object::new(TypesLike::Svg(svg_holder));
+
+        Ok(layer)
+    }
+
+    /// Creates an image layer from a png, jpeg or bmp file, the image is placed with its original
+    /// size at `coordinates`.
+    ///
+    /// To animate the image use the id of the [`Object`] of the layer.
+    pub fn from_image_file(
+        resolution: Resolution,
+        coordinates: Point,
+        path: impl AsRef<Path>,
+    ) -> Result<Self> {
+        let image_holder = ImageHolder::from_file(coordinates, path)?;
+
+        let mut layer = Layer::new(LayerType::Svg, resolution);
+        layer.object = Object::new(TypesLike::Image(image_holder));
+
+        Ok(layer)
+    }
+
+    /// Creates a text layer, the glyphs or words can be animated with the ids from
+    /// [`TextHolder::ids`].
+    pub fn from_text(resolution: Resolution, text_holder: TextHolder) -> Self {
+        let mut layer = Layer::new(LayerType::Svg, resolution);
+        layer.object = Object::new(TypesLike::Text(text_holder));
+
+        layer
+    }
+
+    /// Creates a layer from a nested composition, it's placed with its own resolution at
+    /// `coordinates`.
+    ///
+    /// To position or scale the nested composition use the id of the [`Object`] of the layer.
+    pub fn from_composition(
+        resolution: Resolution,
+        coordinates: Point,
+        precomp: impl Into<Precomp>,
+    ) -> Result<Self> {
+        let precomp = precomp.into();
+        let size = precomp.composition().resolution();
+        let image_holder =
+            ImageHolder::new_fit(coordinates, Plane::new(size.width(), size.height())?);
+
+        let mut layer = Layer::new(LayerType::Svg, resolution);
+        layer.object = Object::new(TypesLike::Composition(image_holder));
+        layer.precomp = Some(precomp);
+
+        Ok(layer)
+    }
+
+    /// Updates the animations at the frame `frame_count` of the composition, the frame is mapped
+    /// onto the timeline of the layer with its [`Timing`]. An inactive layer isn't updated.
+    ///
+    /// The current frame of a nested composition is rendered after the animations.
     pub fn update(&mut self, frame_count: usize) -> Result<()> {
+        if !self.is_active(frame_count) {
+            return Ok(());
+        }
+        let frame_count = match self.timing.local_frame(frame_count) {
+            Some(frame_count) => frame_count,
+            None => return self.update_precomp(None),
+        };
+
         for animation in &self.animations {
             let id = animation.object_id();
 
@@ -80,15 +202,143 @@ impl Layer {
                     AnimationType::ChangeColor(animation) => {
                         Transform::Color(Some(animation.get_value(frame_count)))
                     }
+                    AnimationType::Opacity(animation) => {
+                        Transform::Opacity(animation.get_value(frame_count))
+                    }
+                    AnimationType::Stroke(animation) => {
+                        Transform::Stroke(Some(animation.get_value(frame_count)))
+                    }
+                    AnimationType::Trim(animation) => {
+                        let (start, end) = animation.get_value(frame_count);
+                        Transform::Trim(start, end)
+                    }
+                    AnimationType::Morph(animation) => {
+                        Transform::Path(animation.get_value(frame_count))
+                    }
+                    AnimationType::Gradient(animation) => {
+                        Transform::Color(Some(animation.get_value(frame_count)))
+                    }
+                    AnimationType::ChangeGradient(animation) => {
+                        Transform::Color(Some(animation.get_value(frame_count)))
+                    }
+                    AnimationType::Keyframe(animation) => match animation.get_value(frame_count) {
+                        Some(transformation) => transformation,
+                        None => continue,
+                    },
+                    AnimationType::MotionPath(animation) => {
+                        match animation.get_value(frame_count) {
+                            (Some(position), rotation) => {
+                                if let Some(angle) = rotation {
+                                    self.object.transform_by_id(id, &Transform::Rotate(angle))?;
+                                }
+
+                                Transform::Position(position)
+                            }
+                            (None, _) => continue,
+                        }
+                    }
+                    AnimationType::Rotate(animation) => {
+                        if let Some(pivot) = animation.pivot() {
+                            self.object
+                                .transform_by_id(id, &Transform::Origin(Some(pivot)))?;
+                        }
+
+                        Transform::Rotate(animation.get_value(frame_count))
+                    }
+                    AnimationType::Scale(animation) => {
+                        Transform::Scale(animation.get_value(frame_count))
+                    }
                 };
 
                 self.object.transform_by_id(id, &transformation)?;
             }
         }
 
+        self.update_precomp(Some(frame_count))
+    }
+
+    fn update_precomp(&mut self, frame_count: Option<usize>) -> Result<()> {
+        if let Some(precomp) = &mut self.precomp {
+            let plane = precomp.render(frame_count)?;
+
+            if let TypesLike::Composition(image_holder) = self.object.data_mut() {
+                image_holder.set_data(plane);
+            }
+        }
+
         Ok(())
     }
 
+    /// Calculates the frames of all animations in time with `framerate`, see
+    /// [`Animation::resolve_time`].
+    pub fn resolve_time(&mut self, framerate: FPS) {
+        self.timing.resolve_time(framerate);
+
+        if let Some(precomp) = &mut self.precomp {
+            precomp.resolve_time(framerate);
+        }
+
+        for animation in &mut self.animations {
+            animation.resolve_time(framerate);
+        }
+    }
+
+    pub fn precomp(&self) -> Option<&Precomp> {
+        self.precomp.as_ref()
+    }
+
+    pub fn precomp_mut(&mut self) -> Option<&mut Precomp> {
+        self.precomp.as_mut()
+    }
+
+    pub fn timing(&self) -> &Timing {
+        &self.timing
+    }
+
+    pub fn timing_mut(&mut self) -> &mut Timing {
+        &mut self.timing
+    }
+
+    /// If the layer is visible at the `frame` of the composition, inactive layers aren't
+    /// rendered.
+    pub fn is_active(&self, frame: usize) -> bool {
+        self.timing.is_active(frame)
+    }
+
+    /// Shows the layer only in the frames of `in_out`, see [`Timing::is_active`].
+    pub fn set_in_out(&mut self, in_out: impl Into<Range>) {
+        self.timing.set_in_out(Some(in_out.into()));
+    }
+
+    /// Starts the timeline of the layer's animations at the frame `offset` of the composition,
+    /// a negative offset skips the beginning of the timeline.
+    pub fn set_time_offset(&mut self, offset: isize) {
+        self.timing.set_offset(offset);
+    }
+
+    /// Plays the animations of the layer `speed` times as fast, `0.5` is half the speed.
+    pub fn set_speed(&mut self, speed: f64) {
+        self.timing.set_speed(speed);
+    }
+
+    /// How the layer is mixed with the layers beneath it.
+    pub fn blend_mode(&self) -> BlendMode {
+        self.blend_mode
+    }
+
+    pub fn set_blend_mode(&mut self, blend_mode: BlendMode) {
+        self.blend_mode = blend_mode;
+    }
+
+    pub fn mask(&self) -> Option<&Mask> {
+        self.mask.as_ref()
+    }
+
+    /// Hides parts of the layer with a [`Mask`], a layer which is used as matte isn't visible.
+    pub fn set_mask(&mut self, mask: Option<Mask>) {
+        self.mask = mask;
+    }
+
     pub fn add_animation(&mut self, animation: AnimationType) {
         self.animations.push(animation)
     }
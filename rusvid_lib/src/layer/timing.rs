@@ -0,0 +1,137 @@
+use crate::animation::Range;
+use crate::types::FPS;
+
+/// When a layer is visible and how the frames of the composition are mapped onto the timeline of
+/// the layer's animations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct Timing {
+    /// Frames of the composition in which the layer is visible, `None` for the whole composition
+    in_out: Option<Range>,
+
+    /// Frame of the composition at which the timeline of the layer starts
+    offset: isize,
+
+    /// Frames of the layer's timeline per frame of the composition
+    speed: f64,
+}
+
+impl Default for Timing {
+    fn default() -> Self {
+        Timing {
+            in_out: None,
+            offset: 0,
+            speed: 1.0,
+        }
+    }
+}
+
+impl Timing {
+    pub fn in_out(&self) -> Option<Range> {
+        self.in_out
+    }
+
+    pub fn set_in_out(&mut self, in_out: Option<Range>) {
+        self.in_out = in_out;
+    }
+
+    pub fn offset(&self) -> isize {
+        self.offset
+    }
+
+    pub fn set_offset(&mut self, offset: isize) {
+        self.offset = offset;
+    }
+
+    pub fn speed(&self) -> f64 {
+        self.speed
+    }
+
+    pub fn set_speed(&mut self, speed: f64) {
+        self.speed = speed;
+    }
+
+    /// If the layer is visible at the `frame` of the composition.
+    pub fn is_active(&self, frame: usize) -> bool {
+        match self.in_out {
+            Some(in_out) => in_out.contains(frame),
+            None => true,
+        }
+    }
+
+    /// Frame of the layer's timeline at the `frame` of the composition, `None` if the timeline
+    /// hasn't started yet.
+    ///
+    /// ```rust
+    /// use rusvid_lib::layer::Timing;
+    ///
+    /// let mut timing = Timing::default();
+    /// timing.set_offset(10);
+    /// timing.set_speed(2.0);
+    ///
+    /// assert_eq!(timing.local_frame(5), None);
+    /// assert_eq!(timing.local_frame(10), Some(0));
+    /// assert_eq!(timing.local_frame(13), Some(6));
+    /// ```
+    pub fn local_frame(&self, frame: usize) -> Option<usize> {
+        let local = ((frame as f64 - self.offset as f64) * self.speed).floor();
+
+        match local >= 0.0 {
+            true => Some(local as usize),
+            false => None,
+        }
+    }
+
+    /// Calculates the frames of an in and out point in time, see [`Range::resolve`].
+    pub fn resolve_time(&mut self, framerate: FPS) {
+        if let Some(in_out) = &mut self.in_out {
+            in_out.resolve(framerate);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_is_identity() {
+        let timing = Timing::default();
+
+        for frame in [0, 1, 17, 1000] {
+            assert!(timing.is_active(frame));
+            assert_eq!(timing.local_frame(frame), Some(frame));
+        }
+    }
+
+    #[test]
+    fn in_out() {
+        let mut timing = Timing::default();
+        timing.set_in_out(Some((10..20).into()));
+
+        assert!(!timing.is_active(9));
+        assert!(timing.is_active(10));
+        assert!(timing.is_active(19));
+        assert!(!timing.is_active(20));
+
+        timing.set_in_out(Some(Range::seconds(1.0, 2.0)));
+        timing.resolve_time(10);
+        assert!(!timing.is_active(9));
+        assert!(timing.is_active(10));
+    }
+
+    #[test]
+    fn offset_and_speed() {
+        let mut timing = Timing::default();
+        timing.set_offset(-5);
+        assert_eq!(timing.local_frame(0), Some(5));
+
+        timing.set_offset(4);
+        timing.set_speed(0.5);
+        assert_eq!(timing.local_frame(3), None);
+        assert_eq!(timing.local_frame(4), Some(0));
+        assert_eq!(timing.local_frame(5), Some(0));
+        assert_eq!(timing.local_frame(6), Some(1));
+    }
+}
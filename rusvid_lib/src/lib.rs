@@ -14,20 +14,35 @@ pub use {rusvid_core as core, rusvid_effect as effect};
 
 pub mod prelude {
     pub use crate::animation::change_color_animation::ChangeColorAnimation;
+    pub use crate::animation::change_gradient_animation::ChangeGradientAnimation;
+    pub use crate::animation::gradient_animation::GradientAnimation;
+    pub use crate::animation::keyframe::{
+        Keyframe, KeyframeAnimation, KeyframeProperty, KeyframeTrack,
+    };
+    pub use crate::animation::morph_animation::MorphAnimation;
+    pub use crate::animation::motion_path_animation::MotionPathAnimation;
+    pub use crate::animation::opacity_animation::OpacityAnimation;
     pub use crate::animation::position_animation::PositionAnimation;
+    pub use crate::animation::rotate_animation::RotateAnimation;
+    pub use crate::animation::scale_animation::ScaleAnimation;
     pub use crate::animation::set_color_animation::SetColorAnimation;
+    pub use crate::animation::stroke_animation::StrokeAnimation;
+    pub use crate::animation::trim_animation::TrimAnimation;
     pub use crate::animation::{Animation, AnimationType};
     pub use crate::composition::{Composition, CompositionBuilder};
     pub use crate::core::prelude::*;
     pub use crate::effect::library::*;
     pub use crate::effect::{EffectLogic, Element};
-    pub use crate::layer::{Layer, LayerType};
+    pub use crate::layer::{
+        BlendMode, Layer, LayerType, Mask, MaskSource, MatteMode, Precomp, Timing,
+    };
     pub use crate::metrics::{MetricsSize, MetricsVideo};
     pub use crate::renderer::embedded::EmbeddedRenderer;
     pub use crate::renderer::ffmpeg::FfmpegRenderer;
     pub use crate::renderer::frame::FrameRenderer;
     #[cfg(feature = "remote_renderer")]
     pub use crate::renderer::remote::RemoteRenderer;
+    pub use crate::renderer::svg::SvgRenderer;
     pub use crate::renderer::Renderer;
     pub use crate::resolution::Resolution;
     pub use crate::types::*;
@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::ffi::OsString;
 use std::path::Path;
 use std::process::Command;
@@ -6,12 +7,14 @@ use anyhow::{bail, Result};
 use rusvid_core::plane::Plane;
 
 use crate::composition::Composition;
+use crate::layer::Mask;
 
 pub mod embedded;
 pub mod ffmpeg;
 pub mod frame;
 #[cfg(feature = "remote_renderer")]
 pub mod remote;
+pub mod svg;
 mod util;
 
 use util::combine_renders;
@@ -23,34 +26,78 @@ pub trait Renderer {
     fn tmp_dir_path(&self) -> &Path;
 
     fn render_single(&self, composition: &Composition) -> Result<Plane> {
-        let layers = composition.get_layers();
-        if layers.is_empty() {
-            bail!("No layers in composition");
-        }
-
-        let resolution = composition.resolution();
+        render_composition(composition)
+    }
+}
 
-        let mut frames = Vec::new();
-        for layer in layers {
-            let mut plane = layer
-                .object
-                .render(resolution.width(), resolution.height())?;
+/// Renders the current frame of the composition, nested compositions are rendered the same way.
+pub fn render_composition(composition: &Composition) -> Result<Plane> {
+    let layers = composition.get_layers();
+    if layers.is_empty() {
+        bail!("No layers in composition");
+    }
 
-            for effect in &layer.effects {
-                plane = effect.apply(plane)?;
-            }
+    let resolution = composition.resolution();
+    let frame = composition.frame();
 
-            frames.push(plane);
+    // inactive layers are neither rendered nor used as matte
+    let mut planes = Vec::new();
+    for layer in layers {
+        if !layer.is_active(frame) {
+            planes.push(None);
+            continue;
         }
 
-        let mut combined = combine_renders(resolution.width(), resolution.height(), frames)?;
+        let mut plane = layer
+            .object
+            .render(resolution.width(), resolution.height())?;
 
-        for effect in &composition.effects {
-            combined = effect.apply(combined)?;
+        for effect in &layer.effects {
+            plane = effect.apply(plane)?;
         }
 
-        Ok(combined)
+        planes.push(Some(plane));
+    }
+
+    let coverages = layers
+        .iter()
+        .map(|layer| match layer.is_active(frame) {
+            false => Ok(None),
+            true => layer
+                .mask()
+                .map(|mask| mask.coverage(layers, &planes, resolution.width(), resolution.height()))
+                .transpose(),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // like track mattes the layers which are used as matte aren't visible
+    let mattes = layers
+        .iter()
+        .filter_map(|layer| layer.mask().and_then(Mask::layer_id))
+        .collect::<HashSet<&str>>();
+
+    let frames = layers
+        .iter()
+        .zip(planes)
+        .zip(coverages)
+        .filter(|((layer, _), _)| !mattes.contains(layer.object.id()))
+        .filter_map(|((layer, plane), coverage)| {
+            plane.map(|plane| (plane, layer.blend_mode(), coverage))
+        })
+        .collect();
+
+    let mut combined = combine_renders(
+        resolution.width(),
+        resolution.height(),
+        frames,
+        composition.color_space(),
+    )?;
+
+    for effect in &composition.effects {
+        combined = effect.apply(combined)?;
     }
+
+    Ok(combined)
 }
 
 pub trait CliArgument {
@@ -0,0 +1,62 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use tracing::{debug, info};
+
+use crate::composition::Composition;
+use crate::metrics::MetricsVideo;
+use crate::renderer::Renderer;
+
+/// Saves every frame of the composition as `.svg` file, see [`Composition::to_svg_string`].
+#[derive(Debug)]
+pub struct SvgRenderer {
+    out_dir: PathBuf,
+}
+
+impl SvgRenderer {
+    pub fn new(out_dir: impl Into<PathBuf>) -> Self {
+        SvgRenderer {
+            out_dir: out_dir.into(),
+        }
+    }
+}
+
+impl Renderer for SvgRenderer {
+    fn render(&mut self, mut composition: Composition) -> Result<()> {
+        info!("Using renderer: {:?}", self);
+
+        let out_dir = self.out_dir.to_path_buf();
+
+        if out_dir.exists() {
+            fs::remove_dir_all(&out_dir)?;
+        }
+        fs::create_dir(&out_dir)?;
+
+        let frames = composition.frames();
+        info!("frames: {}", frames);
+        let frame_number_width = frames.to_string().len();
+
+        for i in 0..frames {
+            let frame_number_formatted = format!("{:01$}", i + 1, frame_number_width);
+            info!("frame: {}", frame_number_formatted);
+
+            composition.update(i)?;
+
+            let frame_path =
+                out_dir.join(Path::new(&format!("frame_{frame_number_formatted}.svg")));
+            fs::write(&frame_path, composition.to_svg_string()?)?;
+            debug!("Saved frame at {:?}", frame_path);
+        }
+
+        Ok(())
+    }
+
+    fn out_path(&self) -> &Path {
+        &self.out_dir
+    }
+
+    fn tmp_dir_path(&self) -> &Path {
+        &self.out_dir
+    }
+}
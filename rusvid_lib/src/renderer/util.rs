@@ -1,62 +1,61 @@
 use anyhow::Result;
 use rayon::prelude::*;
-use rusvid_core::pixel::Pixel;
 use rusvid_core::plane::Plane;
+use rusvid_core::working_plane::{ColorSpace, WorkingPixel, WorkingPlane};
 
 use crate::effect::EffectLogic;
-
-pub fn combine_renders(width: u32, height: u32, images: Vec<Plane>) -> Result<Plane> {
-    let images_as_data = images
+use crate::layer::BlendMode;
+
+/// Places the images from the first to the last over each other, every image is mixed with the
+/// images beneath it by its [`BlendMode`]. The optional coverage from `0.0` to `1.0` per pixel
+/// hides parts of an image, see [`crate::layer::Mask`].
+///
+/// The images are combined with premultiplied colors in `color_space` and are only rounded to the
+/// final colors at the end.
+pub fn combine_renders(
+    width: u32,
+    height: u32,
+    images: Vec<(Plane, BlendMode, Option<Vec<f32>>)>,
+    color_space: ColorSpace,
+) -> Result<Plane> {
+    let images = images
         .par_iter()
-        .map(|i| i.as_data())
-        .collect::<Vec<&Vec<Pixel>>>();
-
-    let data = (0..((width * height) as usize))
-        .into_par_iter()
-        .map(|i| {
-            images_as_data.iter().fold(Pixel::ZERO, |acc, value| {
-                let value = value[i];
-
-                match (acc[3], value[3]) {
-                    (0, 0) => acc, // both colors are fully transparent -> do nothing
-                    (_, 0) => acc, // new color is fully transparent -> do nothing
-                    // old color is transparent and the new color overrides it completely
-                    (0, _) => value,
-                    // mix both colors into a new one
-                    (255, 255) => {
-                        // TODO add flag if the layer should override the old one or "merge", if merge then use calculation from beneath match closure
-                        value
-                    }
-                    // mix both colors into a new one
-                    (_, _) => {
-                        let bg_r = (acc[0] as f64) / 255.0;
-                        let bg_g = (acc[1] as f64) / 255.0;
-                        let bg_b = (acc[2] as f64) / 255.0;
-                        let bg_a = (acc[3] as f64) / 255.0;
-
-                        let fg_r = (value[0] as f64) / 255.0;
-                        let fg_g = (value[1] as f64) / 255.0;
-                        let fg_b = (value[2] as f64) / 255.0;
-                        let fg_a = (value[3] as f64) / 255.0;
-
-                        let mix_a = 1.0 - (1.0 - fg_a) * (1.0 - bg_a);
-                        let mix_r = fg_r * fg_a / mix_a + bg_r * bg_a * (1.0 - fg_a) / mix_a;
-                        let mix_g = fg_g * fg_a / mix_a + bg_g * bg_a * (1.0 - fg_a) / mix_a;
-                        let mix_b = fg_b * fg_a / mix_a + bg_b * bg_a * (1.0 - fg_a) / mix_a;
-
-                        Pixel::new(
-                            (mix_r * 255.0) as u8,
-                            (mix_g * 255.0) as u8,
-                            (mix_b * 255.0) as u8,
-                            (mix_a * 255.0) as u8,
-                        )
-                    }
-                }
-            })
+        .map(|(i, blend_mode, coverage)| {
+            (
+                WorkingPlane::from_plane(i, color_space),
+                *blend_mode,
+                coverage.as_ref(),
+            )
         })
-        .collect();
-
-    Ok(Plane::from_data_unchecked(width, height, data))
+        .collect::<Vec<(WorkingPlane, BlendMode, Option<&Vec<f32>>)>>();
+
+    let mut combined = WorkingPlane::new(width, height, color_space);
+    combined
+        .as_data_mut()
+        .par_iter_mut()
+        .enumerate()
+        .for_each(|(i, pixel)| {
+            *pixel =
+                images
+                    .iter()
+                    .fold(WorkingPixel::ZERO, |acc, (image, blend_mode, coverage)| {
+                        let value = &match coverage {
+                            Some(coverage) => image.as_data()[i].with_opacity(coverage[i]),
+                            None => image.as_data()[i],
+                        };
+
+                        match (acc.alpha(), value.alpha()) {
+                            // new color is fully transparent -> do nothing
+                            (_, alpha) if alpha <= 0.0 => acc,
+                            // old color is transparent and the new color overrides it completely
+                            (alpha, _) if alpha <= 0.0 => *value,
+                            // mix both colors into a new one
+                            (_, _) => blend_mode.composite(&acc, value),
+                        }
+                    });
+        });
+
+    Ok(combined.to_plane())
 }
 
 pub fn apply_effects(original: Plane, effects: &Vec<Box<dyn EffectLogic>>) -> Result<Plane> {
@@ -71,6 +70,8 @@ pub fn apply_effects(original: Plane, effects: &Vec<Box<dyn EffectLogic>>) -> Re
 
 #[cfg(test)]
 mod tests {
+    use rusvid_core::pixel::Pixel;
+
     fn generate_plane(width: u32, height: u32) -> Plane {
         let data = (0..(width * height))
             .map(|i| {
@@ -64,3 +64,686 @@ fn renders_correctly_static() {
 fn todo() {
     // TODO
 }
+
+mod dummy;
+
+mod opacity {
+    use rusvid_core::pixel::Pixel;
+    use rusvid_core::point::Point;
+    use rusvid_lib::animation::{EaseType, FunctionType};
+    use rusvid_lib::prelude::*;
+
+    use super::dummy::{add_rect, alpha_at};
+
+    // composition with a red rect which covers the whole frame
+    fn composition() -> (Composition, String) {
+        let mut composition = Composition::builder()
+            .resolution(Resolution::Custom(10, 10))
+            .framerate(3)
+            .duration(1)
+            .build();
+
+        let (_, rect_id) = add_rect(
+            &mut composition,
+            Point::ZERO,
+            Point::new(10.0, 10.0),
+            Pixel::new(255, 0, 0, 255),
+        );
+
+        (composition, rect_id)
+    }
+
+    #[test]
+    fn fades_in_item() {
+        let (mut composition, rect_id) = composition();
+        composition.get_layers_mut()[0].add_animation(AnimationType::Opacity(
+            OpacityAnimation::new(
+                &rect_id,
+                0..=2,
+                (0.0, 1.0),
+                FunctionType::Linear,
+                EaseType::In,
+            ),
+        ));
+
+        assert_eq!(alpha_at(&mut composition, 0, 5, 5), 0);
+        assert_eq!(alpha_at(&mut composition, 1, 5, 5), 128);
+        assert_eq!(alpha_at(&mut composition, 2, 5, 5), 255);
+    }
+
+    #[test]
+    fn fades_out_layer() {
+        let (mut composition, _) = composition();
+        let layer = &mut composition.get_layers_mut()[0];
+        let object_id = layer.object.id().to_string();
+        layer.add_animation(AnimationType::Opacity(OpacityAnimation::new(
+            &object_id,
+            0..=2,
+            (1.0, 0.0),
+            FunctionType::Linear,
+            EaseType::In,
+        )));
+
+        assert_eq!(alpha_at(&mut composition, 0, 5, 5), 255);
+        assert_eq!(alpha_at(&mut composition, 1, 5, 5), 128);
+        assert_eq!(alpha_at(&mut composition, 2, 5, 5), 0);
+    }
+}
+
+mod stroke {
+    use rusvid_core::holder::stroke::Stroke;
+    use rusvid_lib::animation::{EaseType, FunctionType};
+    use rusvid_lib::prelude::*;
+
+    #[test]
+    fn interpolates_width_and_dashoffset() {
+        let stroke = Stroke {
+            dasharray: Some(vec![4.0, 2.0]),
+            ..Stroke::default()
+        };
+        let animation = StrokeAnimation::new(
+            &"line",
+            0..=4,
+            stroke.clone(),
+            (0.0, 2.0),
+            (6.0, 0.0),
+            FunctionType::Linear,
+            EaseType::In,
+        );
+
+        let value = animation.get_value(0);
+        assert_eq!((value.width, value.dashoffset), (0.0, 6.0));
+
+        let value = animation.get_value(2);
+        assert_eq!((value.width, value.dashoffset), (1.0, 3.0));
+        assert_eq!(value.dasharray, stroke.dasharray);
+
+        let value = animation.get_value(4);
+        assert_eq!((value.width, value.dashoffset), (2.0, 0.0));
+    }
+}
+
+mod trim {
+    use rusvid_core::holder::likes::{ColorLike, PathLike};
+    use rusvid_core::holder::stroke::Stroke;
+    use rusvid_core::holder::svg_item::SvgItem;
+    use rusvid_core::holder::transform::{Transform, TransformLogic};
+    use rusvid_core::pixel::Pixel;
+    use rusvid_core::point::Point;
+    use rusvid_lib::animation::{EaseType, FunctionType};
+    use rusvid_lib::prelude::*;
+
+    use super::dummy::alpha_at;
+
+    #[test]
+    fn draws_on_a_line() {
+        let mut composition = Composition::builder()
+            .resolution(Resolution::Custom(10, 10))
+            .framerate(3)
+            .duration(1)
+            .build();
+
+        let layer = composition.create_layer(LayerType::Svg).unwrap();
+        let mut line = SvgItem::new(
+            vec![
+                PathLike::Move(Point::new(0.0, 5.0)),
+                PathLike::Line(Point::new(10.0, 5.0)),
+            ],
+            None,
+        );
+        line.transform(&Transform::Stroke(Some(Stroke {
+            paint: ColorLike::Color(Pixel::new(255, 0, 0, 255)),
+            width: 4.0,
+            ..Stroke::default()
+        })))
+        .unwrap();
+        let line_id = layer.add_svg_item(line).unwrap();
+
+        layer.add_animation(AnimationType::Trim(TrimAnimation::new(
+            &line_id,
+            0..=2,
+            ((0.0, 0.0), (0.0, 1.0)),
+            FunctionType::Linear,
+            EaseType::In,
+        )));
+
+        assert_eq!(alpha_at(&mut composition, 0, 2, 5), 0);
+        assert_eq!(alpha_at(&mut composition, 1, 2, 5), 255);
+        assert_eq!(alpha_at(&mut composition, 1, 8, 5), 0);
+        assert_eq!(alpha_at(&mut composition, 2, 8, 5), 255);
+    }
+}
+
+mod morph {
+    use approx::assert_abs_diff_eq;
+    use rusvid_core::holder::likes::{PathLike, PathMeasure};
+    use rusvid_core::pixel::Pixel;
+    use rusvid_core::point::Point;
+    use rusvid_lib::animation::{EaseType, FunctionType};
+    use rusvid_lib::figures::circle::circle;
+    use rusvid_lib::figures::rect::rect;
+    use rusvid_lib::prelude::*;
+
+    use super::dummy::{add_rect, alpha_at};
+
+    #[test]
+    fn morphs_circle_into_rect() {
+        let circle = circle(Point::new(5.0, 5.0), 5.0);
+        let rect = rect(Point::ZERO, Point::new(10.0, 10.0));
+
+        let animation = MorphAnimation::new(
+            &"shape",
+            0..=4,
+            (&circle, &rect),
+            FunctionType::Linear,
+            EaseType::In,
+        );
+
+        let start = animation.get_value(0);
+        let middle = animation.get_value(2);
+        let end = animation.get_value(4);
+        assert_eq!(start.len(), end.len());
+        assert_eq!(middle.len(), end.len());
+
+        assert_abs_diff_eq!(
+            PathMeasure::new(&start).length(),
+            PathMeasure::new(&circle).length(),
+            epsilon = 0.001
+        );
+        assert_abs_diff_eq!(PathMeasure::new(&end).length(), 40.0, epsilon = 0.001);
+
+        let (min, max) = PathLike::bounding_box(&end);
+        assert_abs_diff_eq!(min, Point::ZERO, epsilon = 0.001);
+        assert_abs_diff_eq!(max, Point::new(10.0, 10.0), epsilon = 0.001);
+    }
+
+    #[test]
+    fn renders_the_morphed_path() {
+        let mut composition = Composition::builder()
+            .resolution(Resolution::Custom(10, 10))
+            .framerate(3)
+            .duration(1)
+            .build();
+
+        let (layer, rect_id) = add_rect(
+            &mut composition,
+            Point::ZERO,
+            Point::new(2.0, 2.0),
+            Pixel::new(255, 0, 0, 255),
+        );
+
+        layer.add_animation(AnimationType::Morph(MorphAnimation::new(
+            &rect_id,
+            0..=2,
+            (
+                &rect(Point::ZERO, Point::new(2.0, 2.0)),
+                &rect(Point::ZERO, Point::new(10.0, 10.0)),
+            ),
+            FunctionType::Linear,
+            EaseType::In,
+        )));
+
+        assert_eq!(alpha_at(&mut composition, 0, 8, 8), 0);
+        assert_eq!(alpha_at(&mut composition, 2, 8, 8), 255);
+    }
+}
+
+mod gradient {
+    use rusvid_core::holder::gradient::base::BaseGradient;
+    use rusvid_core::holder::gradient::linear::LinearGradient;
+    use rusvid_core::holder::likes::ColorLike;
+    use rusvid_core::holder::transform::{Transform, TransformLogic};
+    use rusvid_core::pixel::Pixel;
+    use rusvid_core::point::Point;
+    use rusvid_lib::animation::{EaseType, FunctionType};
+    use rusvid_lib::prelude::*;
+
+    use super::dummy::{pixel_at, rect_item};
+
+    #[test]
+    fn rotates_the_gradient() {
+        let mut composition = Composition::builder()
+            .resolution(Resolution::Custom(10, 10))
+            .framerate(3)
+            .duration(1)
+            .build();
+
+        let gradient =
+            ColorLike::LinearGradient(LinearGradient::new(BaseGradient::new_from_colors(vec![
+                Pixel::new(255, 0, 0, 255),
+                Pixel::new(0, 0, 255, 255),
+            ])));
+
+        let mut item = rect_item(
+            Point::ZERO,
+            Point::new(10.0, 10.0),
+            Pixel::new(255, 0, 0, 255),
+        );
+        item.transform(&Transform::Color(Some(gradient.clone())))
+            .unwrap();
+
+        let layer = composition.create_layer(LayerType::Svg).unwrap();
+        let rect_id = layer.add_svg_item(item).unwrap();
+
+        layer.add_animation(AnimationType::Gradient(GradientAnimation::new(
+            &rect_id,
+            0..=2,
+            gradient,
+            (0.0, std::f64::consts::PI),
+            (0.0, 0.0),
+            FunctionType::Linear,
+            EaseType::In,
+        )));
+
+        let left = pixel_at(&mut composition, 0, 0, 5);
+        assert!(left[0] > 200 && left[2] < 50);
+
+        let left = pixel_at(&mut composition, 2, 0, 5);
+        assert!(left[0] < 50 && left[2] > 200);
+    }
+}
+
+mod change_gradient {
+    use rusvid_core::holder::gradient::base::BaseGradient;
+    use rusvid_core::holder::gradient::radial::RadialGradient;
+    use rusvid_core::holder::likes::ColorLike;
+    use rusvid_core::pixel::Pixel;
+    use rusvid_core::point::Point;
+    use rusvid_lib::animation::{EaseType, FunctionType};
+    use rusvid_lib::prelude::*;
+
+    #[test]
+    fn tweens_solid_color_to_gradient() {
+        let gradient = ColorLike::RadialGradient(RadialGradient::new(
+            Point::new(0.5, 0.5),
+            0.5,
+            Point::new(0.5, 0.5),
+            BaseGradient::new_from_colors(vec![
+                Pixel::new(255, 255, 255, 255),
+                Pixel::new(0, 0, 255, 255),
+            ]),
+        ));
+
+        let animation = ChangeGradientAnimation::new(
+            &"circle",
+            0..=2,
+            (
+                ColorLike::Color(Pixel::new(255, 0, 0, 255)),
+                gradient.clone(),
+            ),
+            FunctionType::Linear,
+            EaseType::In,
+        );
+
+        match animation.get_value(1) {
+            ColorLike::RadialGradient(r_g) => {
+                assert_eq!(r_g.base().color_at(0.0), Pixel::new(255, 128, 128, 255));
+                assert_eq!(r_g.base().color_at(1.0), Pixel::new(128, 0, 128, 255));
+            }
+            _ => panic!("expected a radial gradient"),
+        }
+        assert_eq!(animation.get_value(2), gradient);
+    }
+}
+
+mod keyframe {
+    use rusvid_core::pixel::Pixel;
+    use rusvid_core::point::Point;
+    use rusvid_lib::animation::{EaseType, FunctionType};
+    use rusvid_lib::prelude::*;
+
+    use super::dummy::{add_rect, alpha_at};
+
+    #[test]
+    fn moves_through_waypoints() {
+        let mut composition = Composition::builder()
+            .resolution(Resolution::Custom(20, 20))
+            .framerate(5)
+            .duration(1)
+            .build();
+
+        let (layer, rect_id) = add_rect(
+            &mut composition,
+            Point::ZERO,
+            Point::new(2.0, 2.0),
+            Pixel::new(255, 0, 0, 255),
+        );
+
+        let track = KeyframeTrack::new()
+            .with_key(0, Point::ZERO, FunctionType::Linear, EaseType::In)
+            .with_key(2, Point::new(10.0, 0.0), FunctionType::Linear, EaseType::In)
+            .with_key(
+                4,
+                Point::new(10.0, 10.0),
+                FunctionType::Linear,
+                EaseType::In,
+            );
+        layer.add_animation(AnimationType::Keyframe(KeyframeAnimation::new(
+            &rect_id,
+            KeyframeProperty::Position(track),
+        )));
+
+        assert_eq!(alpha_at(&mut composition, 0, 1, 1), 255);
+        assert_eq!(alpha_at(&mut composition, 1, 6, 1), 255);
+        assert_eq!(alpha_at(&mut composition, 2, 11, 1), 255);
+        assert_eq!(alpha_at(&mut composition, 3, 11, 6), 255);
+        assert_eq!(alpha_at(&mut composition, 4, 11, 11), 255);
+        assert_eq!(alpha_at(&mut composition, 4, 11, 1), 0);
+    }
+}
+
+mod motion_path {
+    use std::f64::consts::FRAC_PI_2;
+
+    use approx::assert_abs_diff_eq;
+    use rusvid_core::holder::likes::PathLike;
+    use rusvid_core::pixel::Pixel;
+    use rusvid_core::point::Point;
+    use rusvid_lib::animation::{EaseType, FunctionType};
+    use rusvid_lib::prelude::*;
+
+    use super::dummy::{add_rect, pixel_at};
+
+    fn corner() -> Vec<PathLike> {
+        vec![
+            PathLike::Move(Point::ZERO),
+            PathLike::Line(Point::new(10.0, 0.0)),
+            PathLike::Line(Point::new(10.0, 10.0)),
+        ]
+    }
+
+    #[test]
+    fn moves_along_the_path() {
+        let animation =
+            MotionPathAnimation::new(&"id", 0..=4, &corner(), FunctionType::Linear, EaseType::In);
+
+        assert_eq!(animation.get_value(0), (Some(Point::ZERO), None));
+        assert_eq!(animation.get_value(1), (Some(Point::new(5.0, 0.0)), None));
+        assert_eq!(animation.get_value(2), (Some(Point::new(10.0, 0.0)), None));
+        assert_eq!(animation.get_value(3), (Some(Point::new(10.0, 5.0)), None));
+        assert_eq!(animation.get_value(4), (Some(Point::new(10.0, 10.0)), None));
+
+        let empty = MotionPathAnimation::new(&"id", 0..=4, &[], FunctionType::Linear, EaseType::In);
+        assert_eq!(empty.get_value(2), (None, None));
+    }
+
+    #[test]
+    fn orients_along_the_path() {
+        let animation =
+            MotionPathAnimation::new(&"id", 0..=4, &corner(), FunctionType::Linear, EaseType::In)
+                .with_auto_orient(true);
+
+        assert_abs_diff_eq!(animation.get_value(1).1.unwrap(), 0.0);
+        // the path points downwards, which is a clockwise rotation
+        assert_abs_diff_eq!(animation.get_value(3).1.unwrap(), -FRAC_PI_2);
+    }
+
+    fn red_at(auto_orient: bool) -> u8 {
+        let mut composition = Composition::builder()
+            .resolution(Resolution::Custom(20, 20))
+            .framerate(3)
+            .duration(1)
+            .build();
+
+        let (layer, bar_id) = add_rect(
+            &mut composition,
+            Point::ZERO,
+            Point::new(6.0, 2.0),
+            Pixel::new(255, 0, 0, 255),
+        );
+
+        // downwards from (10, 2) to (10, 18)
+        let path = vec![
+            PathLike::Move(Point::new(10.0, 2.0)),
+            PathLike::Line(Point::new(10.0, 18.0)),
+        ];
+        layer.add_animation(AnimationType::MotionPath(
+            MotionPathAnimation::new(&bar_id, 0..=2, &path, FunctionType::Linear, EaseType::In)
+                .with_auto_orient(auto_orient),
+        ));
+
+        pixel_at(&mut composition, 0, 9, 6)[0]
+    }
+
+    #[test]
+    fn renders_the_oriented_item() {
+        // the horizontal bar is rotated into a vertical bar left of the path
+        assert_eq!(red_at(true), 255);
+        assert_eq!(red_at(false), 0);
+    }
+}
+
+mod rotate_and_scale {
+    use std::f64::consts::FRAC_PI_2;
+
+    use rusvid_core::pixel::Pixel;
+    use rusvid_core::point::Point;
+    use rusvid_lib::animation::{EaseType, FunctionType};
+    use rusvid_lib::prelude::*;
+
+    use super::dummy::{add_rect, pixel_at};
+
+    fn composition_with_rect(
+        rect_at: (Point, Point),
+        animation: impl FnOnce(&String) -> AnimationType,
+    ) -> Composition {
+        let mut composition = Composition::builder()
+            .resolution(Resolution::Custom(20, 20))
+            .framerate(3)
+            .duration(1)
+            .build();
+
+        let (layer, rect_id) = add_rect(
+            &mut composition,
+            rect_at.0,
+            rect_at.1,
+            Pixel::new(255, 0, 0, 255),
+        );
+        layer.add_animation(animation(&rect_id));
+
+        composition
+    }
+
+    fn red_at(composition: &mut Composition, frame: usize, x: u32, y: u32) -> u8 {
+        pixel_at(composition, frame, x, y)[0]
+    }
+
+    #[test]
+    fn values() {
+        let rotate = RotateAnimation::new(
+            &"id",
+            0..=4,
+            (0.0, FRAC_PI_2),
+            FunctionType::Linear,
+            EaseType::In,
+        );
+        assert_eq!(rotate.get_value(0), 0.0);
+        assert_eq!(rotate.get_value(2), FRAC_PI_2 / 2.0);
+        assert_eq!(rotate.get_value(4), FRAC_PI_2);
+        assert_eq!(rotate.pivot(), None);
+
+        let scale = ScaleAnimation::new(
+            &"id",
+            0..=4,
+            (Point::ONE, Point::new(3.0, 0.0)),
+            FunctionType::Linear,
+            EaseType::In,
+        );
+        assert_eq!(scale.get_value(0), Point::ONE);
+        assert_eq!(scale.get_value(2), Point::new(2.0, 0.5));
+        assert_eq!(scale.get_value(4), Point::new(3.0, 0.0));
+    }
+
+    #[test]
+    fn rotates_around_the_center() {
+        let mut composition =
+            composition_with_rect((Point::new(5.0, 9.0), Point::new(10.0, 2.0)), |id| {
+                AnimationType::Rotate(RotateAnimation::new(
+                    id,
+                    0..=2,
+                    (0.0, FRAC_PI_2),
+                    FunctionType::Linear,
+                    EaseType::In,
+                ))
+            });
+
+        assert_eq!(red_at(&mut composition, 0, 10, 6), 0);
+        assert_eq!(red_at(&mut composition, 2, 10, 6), 255);
+    }
+
+    #[test]
+    fn rotates_around_the_pivot() {
+        let mut composition =
+            composition_with_rect((Point::new(5.0, 9.0), Point::new(10.0, 2.0)), |id| {
+                AnimationType::Rotate(
+                    RotateAnimation::new(
+                        id,
+                        0..=2,
+                        (0.0, FRAC_PI_2),
+                        FunctionType::Linear,
+                        EaseType::In,
+                    )
+                    .with_pivot(Point::new(5.0, 10.0)),
+                )
+            });
+
+        // the right end of the bar points upwards
+        assert_eq!(red_at(&mut composition, 2, 5, 3), 255);
+        assert_eq!(red_at(&mut composition, 2, 10, 6), 0);
+    }
+
+    #[test]
+    fn scales_non_uniform() {
+        let mut composition =
+            composition_with_rect((Point::new(4.0, 4.0), Point::new(2.0, 2.0)), |id| {
+                AnimationType::Scale(ScaleAnimation::new(
+                    id,
+                    0..=2,
+                    (Point::ONE, Point::new(4.0, 1.0)),
+                    FunctionType::Linear,
+                    EaseType::In,
+                ))
+            });
+
+        assert_eq!(red_at(&mut composition, 0, 1, 5), 0);
+        assert_eq!(red_at(&mut composition, 2, 1, 5), 255);
+        assert_eq!(red_at(&mut composition, 2, 5, 2), 0);
+    }
+}
+
+mod time {
+    use std::time::Duration;
+
+    use rusvid_core::holder::likes::ColorLike;
+    use rusvid_core::pixel::Pixel;
+    use rusvid_core::point::Point;
+    use rusvid_lib::animation::{EaseType, FunctionType};
+    use rusvid_lib::prelude::*;
+
+    use super::dummy::add_rect;
+
+    fn alpha_at(framerate: u8, frame: usize) -> u8 {
+        pixel_at(framerate, frame, |rect_id| {
+            AnimationType::Opacity(OpacityAnimation::new(
+                &rect_id,
+                Duration::ZERO..=Duration::from_secs(1),
+                (0.0, 1.0),
+                FunctionType::Linear,
+                EaseType::In,
+            ))
+        })[3]
+    }
+
+    // renders a red rect which covers the whole frame with the animation from `animation`
+    fn pixel_at(framerate: u8, frame: usize, animation: impl Fn(String) -> AnimationType) -> Pixel {
+        let mut composition = Composition::builder()
+            .resolution(Resolution::Custom(10, 10))
+            .framerate(framerate)
+            .duration(2)
+            .build();
+
+        let (layer, rect_id) = add_rect(
+            &mut composition,
+            Point::ZERO,
+            Point::new(10.0, 10.0),
+            Pixel::new(255, 0, 0, 255),
+        );
+        layer.add_animation(animation(rect_id));
+
+        super::dummy::pixel_at(&mut composition, frame, 5, 5)
+    }
+
+    #[test]
+    fn is_independent_of_the_framerate() {
+        for framerate in [10, 20, 60] {
+            let frames_per_second = framerate as usize;
+
+            assert_eq!(alpha_at(framerate, 0), 0);
+            assert!((127..=128).contains(&alpha_at(framerate, frames_per_second / 2)));
+            assert_eq!(alpha_at(framerate, frames_per_second), 255);
+        }
+    }
+
+    #[test]
+    fn keyframes_are_independent_of_the_framerate() {
+        let keyframes = |rect_id: String| {
+            let track = KeyframeTrack::new()
+                .with_key_at(Duration::ZERO, 0.0, FunctionType::Linear, EaseType::In)
+                .with_key_at(
+                    Duration::from_secs(1),
+                    1.0,
+                    FunctionType::Linear,
+                    EaseType::In,
+                );
+
+            AnimationType::Keyframe(KeyframeAnimation::new(
+                &rect_id,
+                KeyframeProperty::Opacity(track),
+            ))
+        };
+
+        for framerate in [30, 60] {
+            let frames_per_second = framerate as usize;
+
+            assert_eq!(pixel_at(framerate, 0, keyframes)[3], 0);
+            assert!((127..=128).contains(&pixel_at(framerate, frames_per_second / 2, keyframes)[3]));
+            assert_eq!(pixel_at(framerate, frames_per_second, keyframes)[3], 255);
+        }
+    }
+
+    #[test]
+    fn set_color_is_independent_of_the_framerate() {
+        let blue = Pixel::new(0, 0, 255, 255);
+        let set_color = |rect_id: String| {
+            AnimationType::SetColor(SetColorAnimation::at_time(
+                &rect_id,
+                Duration::from_secs(1),
+                Some(ColorLike::Color(blue)),
+            ))
+        };
+
+        for framerate in [30, 60] {
+            let frames_per_second = framerate as usize;
+
+            assert_eq!(
+                pixel_at(framerate, frames_per_second - 1, set_color),
+                Pixel::new(255, 0, 0, 255)
+            );
+            assert_eq!(pixel_at(framerate, frames_per_second, set_color), blue);
+        }
+    }
+
+    #[test]
+    fn frames_still_work() {
+        let animation = OpacityAnimation::new(
+            &"id",
+            0..=10,
+            (0.0, 1.0),
+            FunctionType::Linear,
+            EaseType::In,
+        );
+
+        assert_eq!(animation.get_value(5), 0.5);
+        assert_eq!(animation.end_frame(), 11);
+    }
+}
@@ -0,0 +1,73 @@
+use rusvid_core::pixel::Pixel;
+use rusvid_core::point::Point;
+use rusvid_lib::prelude::*;
+
+mod dummy;
+
+use dummy::{add_rect, DummyRender};
+
+/// Renders a gray rect on the left half with a rect of `color` on top of it.
+fn render(color: Pixel, blend_mode: BlendMode) -> (Pixel, Pixel) {
+    let mut composition = Composition::builder()
+        .resolution(Resolution::Custom(10, 10))
+        .framerate(1)
+        .duration(1)
+        .build();
+
+    let size = Point::new(5.0, 10.0);
+    add_rect(
+        &mut composition,
+        Point::ZERO,
+        size,
+        Pixel::new(128, 128, 128, 255),
+    );
+    add_rect(&mut composition, Point::ZERO, size, color)
+        .0
+        .set_blend_mode(blend_mode);
+
+    let frame = DummyRender::default().render_frame(&composition).unwrap();
+
+    (*frame.pixel_unchecked(2, 5), *frame.pixel_unchecked(7, 5))
+}
+
+#[test]
+fn normal_overrides_the_layer_beneath() {
+    let (overlapping, empty) = render(Pixel::new(255, 0, 0, 255), BlendMode::Normal);
+
+    assert_eq!(overlapping, Pixel::new(255, 0, 0, 255));
+    assert_eq!(empty, Pixel::ZERO);
+}
+
+#[test]
+fn mixes_with_the_layer_beneath() {
+    let red = Pixel::new(255, 0, 0, 255);
+
+    assert_eq!(
+        render(red, BlendMode::Multiply).0,
+        Pixel::new(128, 0, 0, 255)
+    );
+    assert_eq!(
+        render(red, BlendMode::Screen).0,
+        Pixel::new(255, 128, 128, 255)
+    );
+    assert_eq!(
+        render(red, BlendMode::Difference).0,
+        Pixel::new(127, 128, 128, 255)
+    );
+    assert_eq!(
+        render(Pixel::WHITE, BlendMode::Darken).0,
+        Pixel::new(128, 128, 128, 255)
+    );
+}
+
+#[test]
+fn transparent_layers_are_mixed_partially() {
+    let half_red = Pixel::new(255, 0, 0, 128);
+
+    let (normal, _) = render(half_red, BlendMode::Normal);
+    let (multiply, _) = render(half_red, BlendMode::Multiply);
+
+    assert_eq!(normal[3], 255);
+    assert_eq!(multiply[3], 255);
+    assert!(multiply[0] < normal[0]);
+}
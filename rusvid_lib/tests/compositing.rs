@@ -0,0 +1,95 @@
+use rusvid_core::pixel::Pixel;
+use rusvid_core::point::Point;
+use rusvid_core::working_plane::ColorSpace;
+use rusvid_lib::prelude::*;
+
+mod dummy;
+
+use dummy::{add_rect, DummyRender};
+
+fn composition(color_space: ColorSpace) -> Composition {
+    Composition::builder()
+        .resolution(Resolution::Custom(10, 10))
+        .framerate(1)
+        .duration(1)
+        .color_space(color_space)
+        .build()
+}
+
+#[test]
+fn antialiased_edges_have_no_dark_fringes() {
+    for color_space in [ColorSpace::Srgb, ColorSpace::Linear] {
+        let mut composition = composition(color_space);
+        let white = Pixel::new(255, 255, 255, 255);
+
+        add_rect(&mut composition, Point::ZERO, Point::new(10.0, 10.0), white);
+        // the edge at x = 2.5 covers half of the pixel
+        add_rect(&mut composition, Point::ZERO, Point::new(2.5, 10.0), white);
+
+        let frame = DummyRender::default().render_frame(&composition).unwrap();
+
+        assert_eq!(*frame.pixel_unchecked(2, 5), white);
+    }
+}
+
+#[test]
+fn antialiased_edges_are_transparent() {
+    let mut composition = composition(ColorSpace::Srgb);
+    add_rect(
+        &mut composition,
+        Point::ZERO,
+        Point::new(2.5, 10.0),
+        Pixel::new(255, 0, 0, 255),
+    );
+
+    let frame = DummyRender::default().render_frame(&composition).unwrap();
+
+    // the color of the edge isn't darkened by its coverage
+    let edge = frame.pixel_unchecked(2, 5);
+    assert_eq!(edge[0], 255);
+    assert!((127..=128).contains(&edge[3]));
+}
+
+#[test]
+fn layers_are_not_rounded_in_between() {
+    let mut composition = composition(ColorSpace::Srgb);
+    let color = Pixel::new(201, 99, 51, 100);
+
+    for _ in 0..10 {
+        add_rect(&mut composition, Point::ZERO, Point::new(10.0, 10.0), color);
+    }
+
+    let frame = DummyRender::default().render_frame(&composition).unwrap();
+    let pixel = frame.pixel_unchecked(5, 5);
+
+    // the same color over itself doesn't change the color
+    assert_eq!(pixel.to_raw()[0..3], [201, 99, 51]);
+    // 1 - (1 - 100 / 255)^10
+    assert_eq!(pixel[3], 253);
+}
+
+#[test]
+fn mixes_in_linear_light() {
+    let render = |color_space: ColorSpace| {
+        let mut composition = composition(color_space);
+        add_rect(
+            &mut composition,
+            Point::ZERO,
+            Point::new(10.0, 10.0),
+            Pixel::new(0, 0, 0, 255),
+        );
+        add_rect(
+            &mut composition,
+            Point::ZERO,
+            Point::new(10.0, 10.0),
+            Pixel::new(255, 255, 255, 128),
+        );
+
+        let frame = DummyRender::default().render_frame(&composition).unwrap();
+        frame.pixel_unchecked(5, 5)[0]
+    };
+
+    assert_eq!(render(ColorSpace::Srgb), 128);
+    // half of the light is brighter than half of the sRGB value
+    assert_eq!(render(ColorSpace::Linear), 188);
+}
@@ -1,6 +1,18 @@
+// not every test uses every helper
+#![allow(dead_code)]
+
 use anyhow::Result;
+use rusvid_core::holder::likes::ColorLike;
+use rusvid_core::holder::svg_item::SvgItem;
+use rusvid_core::holder::transform::{Transform, TransformLogic};
+use rusvid_core::pixel::Pixel;
 use rusvid_core::plane::Plane;
+use rusvid_core::point::Point;
+use rusvid_lib::animation::opacity_animation::OpacityAnimation;
+use rusvid_lib::animation::{AnimationType, EaseType, FunctionType};
 use rusvid_lib::composition::Composition;
+use rusvid_lib::figures::rect::rect;
+use rusvid_lib::layer::{Layer, LayerType};
 use rusvid_lib::renderer::Renderer;
 
 #[derive(Default)]
@@ -25,3 +37,58 @@ impl DummyRender {
         self.render_single(composition)
     }
 }
+
+/// Filled rect from `position` with `size` and without a stroke.
+pub fn rect_item(position: Point, size: Point, color: Pixel) -> SvgItem {
+    let mut item = SvgItem::new(rect(position, size), Some(ColorLike::Color(color)));
+    item.transform(&Transform::Stroke(None)).unwrap();
+
+    item
+}
+
+/// Adds a svg layer with a [`rect_item`], returns the layer and the id of the rect.
+pub fn add_rect(
+    composition: &mut Composition,
+    position: Point,
+    size: Point,
+    color: Pixel,
+) -> (&mut Layer, String) {
+    let layer = composition.create_layer(LayerType::Svg).unwrap();
+    let rect_id = layer
+        .add_svg_item(rect_item(position, size, color))
+        .unwrap();
+
+    (layer, rect_id)
+}
+
+/// Adds a red rect which covers the whole composition and fades in from frame 0 to 4, returns
+/// the layer.
+pub fn add_fading_rect(composition: &mut Composition) -> &mut Layer {
+    let resolution = composition.resolution();
+    let size = Point::new(resolution.width() as f64, resolution.height() as f64);
+
+    let (layer, rect_id) = add_rect(composition, Point::ZERO, size, Pixel::new(255, 0, 0, 255));
+    layer.add_animation(AnimationType::Opacity(OpacityAnimation::new(
+        &rect_id,
+        0..=4,
+        (0.0, 1.0),
+        FunctionType::Linear,
+        EaseType::In,
+    )));
+
+    layer
+}
+
+/// Updates the composition to `frame` and renders the pixel at `x`, `y`.
+pub fn pixel_at(composition: &mut Composition, frame: usize, x: u32, y: u32) -> Pixel {
+    composition.update(frame).unwrap();
+
+    *DummyRender::default()
+        .render_frame(composition)
+        .unwrap()
+        .pixel_unchecked(x, y)
+}
+
+pub fn alpha_at(composition: &mut Composition, frame: usize, x: u32, y: u32) -> u8 {
+    pixel_at(composition, frame, x, y)[3]
+}
@@ -0,0 +1,94 @@
+use rusvid_core::pixel::Pixel;
+use rusvid_core::point::Point;
+use rusvid_lib::prelude::*;
+use rusvid_lib::renderer::Renderer;
+
+mod dummy;
+
+use dummy::{add_rect, rect_item, DummyRender};
+
+const RED: Pixel = Pixel::new_raw([255, 0, 0, 255]);
+const WHITE: Pixel = Pixel::new_raw([255, 255, 255, 255]);
+const BLACK: Pixel = Pixel::new_raw([0, 0, 0, 255]);
+
+const FULL: Point = Point::new(10.0, 10.0);
+const HALF: Point = Point::new(5.0, 10.0);
+
+fn composition() -> Composition {
+    Composition::builder()
+        .resolution(Resolution::Custom(10, 10))
+        .framerate(1)
+        .duration(1)
+        .build()
+}
+
+/// A red layer masked by a matte with a white left half and optionally a black right half.
+fn render_with_matte(mode: MatteMode, black_right_half: bool) -> (Pixel, Pixel) {
+    let mut composition = composition();
+
+    let (matte, _) = add_rect(&mut composition, Point::ZERO, HALF, WHITE);
+    if black_right_half {
+        matte
+            .add_svg_item(rect_item(Point::new(5.0, 0.0), HALF, BLACK))
+            .unwrap();
+    }
+    let matte_id = matte.object.id().to_string();
+
+    add_rect(&mut composition, Point::ZERO, FULL, RED)
+        .0
+        .set_mask(Some(Mask::layer(matte_id, mode)));
+
+    let frame = DummyRender::default().render_frame(&composition).unwrap();
+
+    (*frame.pixel_unchecked(2, 5), *frame.pixel_unchecked(7, 5))
+}
+
+#[test]
+fn alpha_matte() {
+    // the matte itself isn't visible
+    assert_eq!(
+        render_with_matte(MatteMode::Alpha, false),
+        (RED, Pixel::ZERO)
+    );
+    assert_eq!(
+        render_with_matte(MatteMode::AlphaInverted, false),
+        (Pixel::ZERO, RED)
+    );
+}
+
+#[test]
+fn luma_matte() {
+    assert_eq!(render_with_matte(MatteMode::Luma, true), (RED, Pixel::ZERO));
+    assert_eq!(
+        render_with_matte(MatteMode::LumaInverted, true),
+        (Pixel::ZERO, RED)
+    );
+
+    // the black half is opaque, therefore it would be visible with an alpha matte
+    assert_eq!(render_with_matte(MatteMode::Alpha, true), (RED, RED));
+}
+
+#[test]
+fn clips_to_a_path() {
+    let mut composition = composition();
+
+    let clip = rect_item(Point::ZERO, Point::new(10.0, 5.0), WHITE);
+    add_rect(&mut composition, Point::ZERO, FULL, RED)
+        .0
+        .set_mask(Some(Mask::path(clip, MatteMode::Alpha)));
+
+    let frame = DummyRender::default().render_frame(&composition).unwrap();
+
+    assert_eq!(*frame.pixel_unchecked(5, 2), RED);
+    assert_eq!(*frame.pixel_unchecked(5, 7), Pixel::ZERO);
+}
+
+#[test]
+fn unknown_matte_layer() {
+    let mut composition = composition();
+    add_rect(&mut composition, Point::ZERO, FULL, RED)
+        .0
+        .set_mask(Some(Mask::layer("unknown", MatteMode::Alpha)));
+
+    assert!(DummyRender::default().render_single(&composition).is_err());
+}
@@ -0,0 +1,124 @@
+use rusvid_core::holder::transform::{Transform, TransformLogic};
+use rusvid_core::point::Point;
+use rusvid_lib::prelude::*;
+
+mod dummy;
+
+use dummy::{add_fading_rect, alpha_at};
+
+/// Parent with 10 fps and a nested composition of 4x4 pixels at (2, 2).
+fn composition(nested_framerate: u8, looping: bool) -> Composition {
+    let mut nested = Composition::builder()
+        .resolution(Resolution::Custom(4, 4))
+        .framerate(nested_framerate)
+        .duration(1)
+        .build();
+    add_fading_rect(&mut nested);
+
+    let mut composition = Composition::builder()
+        .resolution(Resolution::Custom(10, 10))
+        .framerate(10)
+        .duration(3)
+        .build();
+
+    let precomp = Precomp::new(nested).with_looping(looping);
+    let layer =
+        Layer::from_composition(composition.resolution(), Point::new(2.0, 2.0), precomp).unwrap();
+    composition.add_layer(layer);
+
+    composition
+}
+
+#[test]
+fn renders_nested_composition() {
+    let mut composition = composition(10, false);
+
+    assert_eq!(alpha_at(&mut composition, 0, 3, 3), 0);
+    assert_eq!(alpha_at(&mut composition, 2, 3, 3), 128);
+    assert_eq!(alpha_at(&mut composition, 4, 3, 3), 255);
+
+    // only the area of the nested composition is covered
+    assert_eq!(alpha_at(&mut composition, 4, 1, 1), 0);
+    assert_eq!(alpha_at(&mut composition, 4, 5, 5), 255);
+    assert_eq!(alpha_at(&mut composition, 4, 6, 6), 0);
+
+    // the nested composition is over after one second
+    assert_eq!(alpha_at(&mut composition, 10, 3, 3), 0);
+}
+
+#[test]
+fn looping() {
+    let mut composition = composition(10, true);
+
+    assert_eq!(alpha_at(&mut composition, 12, 3, 3), 128);
+    assert_eq!(alpha_at(&mut composition, 24, 3, 3), 255);
+}
+
+#[test]
+fn own_framerate() {
+    let mut composition = composition(5, false);
+
+    // frame 4 at 10 fps is frame 2 at 5 fps
+    assert_eq!(alpha_at(&mut composition, 4, 3, 3), 128);
+    assert_eq!(alpha_at(&mut composition, 8, 3, 3), 255);
+}
+
+#[test]
+fn time_offset() {
+    let mut composition = composition(10, false);
+    composition.get_layers_mut()[0].set_time_offset(10);
+
+    assert_eq!(alpha_at(&mut composition, 4, 3, 3), 0);
+    assert_eq!(alpha_at(&mut composition, 12, 3, 3), 128);
+}
+
+#[test]
+fn position_and_scale() {
+    let mut composition = composition(10, false);
+    let layer = &mut composition.get_layers_mut()[0];
+    let object_id = layer.object.id().to_string();
+
+    layer
+        .object
+        .transform_by_id(&object_id, &Transform::Scale(Point::new(2.0, 2.0)))
+        .unwrap();
+
+    // scaled around the center (4, 4) to the box (0, 0) to (8, 8)
+    assert_eq!(alpha_at(&mut composition, 4, 0, 0), 255);
+    assert_eq!(alpha_at(&mut composition, 4, 7, 7), 255);
+    assert_eq!(alpha_at(&mut composition, 4, 8, 8), 0);
+
+    let layer = &mut composition.get_layers_mut()[0];
+    layer
+        .object
+        .transform_by_id(&object_id, &Transform::Scale(Point::ONE))
+        .unwrap();
+    layer
+        .object
+        .transform_by_id(&object_id, &Transform::Position(Point::new(6.0, 6.0)))
+        .unwrap();
+
+    assert_eq!(alpha_at(&mut composition, 4, 3, 3), 0);
+    assert_eq!(alpha_at(&mut composition, 4, 6, 6), 255);
+    assert_eq!(alpha_at(&mut composition, 4, 9, 9), 255);
+}
+
+#[test]
+fn layer_type() {
+    let mut composition = Composition::builder()
+        .resolution(Resolution::Custom(10, 10))
+        .framerate(10)
+        .duration(1)
+        .build();
+
+    let layer = composition.create_layer(LayerType::Composition).unwrap();
+    let nested = layer.precomp_mut().unwrap().composition_mut();
+    assert_eq!(nested.resolution(), Resolution::Custom(10, 10));
+
+    // an empty nested composition is transparent
+    assert_eq!(alpha_at(&mut composition, 0, 5, 5), 0);
+
+    let layer = &mut composition.get_layers_mut()[0];
+    add_fading_rect(layer.precomp_mut().unwrap().composition_mut());
+    assert_eq!(alpha_at(&mut composition, 4, 5, 5), 255);
+}
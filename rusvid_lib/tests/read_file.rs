@@ -1,7 +1,13 @@
-/*
 use std::env;
 
+use rusvid_core::holder::likes::TypesLike;
+use rusvid_core::holder::object::Object;
+use rusvid_core::holder::svg_holder::SvgHolder;
+use rusvid_core::holder::text_holder::{Font, TextHolder, TextOptions};
+use rusvid_core::holder::transform::{Transform, TransformLogic};
 use rusvid_core::pixel::Pixel;
+use rusvid_core::plane::Plane;
+use rusvid_core::point::Point;
 use rusvid_lib::metrics::MetricsVideo;
 use rusvid_lib::prelude::*;
 
@@ -16,14 +22,14 @@ const PIXEL_BLACK: Pixel = Pixel::new_raw([0, 0, 0, 255]);
 #[test]
 fn renders_svg_file() {
     let mut composition = Composition::builder()
-        .resolution(Resolution::Custom(1000, 1000))
+        .resolution(Resolution::Custom(1200, 800))
         .framerate(1)
         .duration(1)
         .build();
 
     let layer = Layer::from_file(
         composition.resolution(),
-        &env::current_dir()
+        env::current_dir()
             .unwrap()
             .as_path()
             .join("./tests/ferris.svg"),
@@ -37,34 +43,143 @@ fn renders_svg_file() {
 
     // corners
     assert_eq!(buffer.pixel_unchecked(0, 0), &PIXEL_TRANSPARENT);
-    assert_eq!(buffer.pixel_unchecked(0, 999), &PIXEL_TRANSPARENT);
-    assert_eq!(buffer.pixel_unchecked(999, 0), &PIXEL_TRANSPARENT);
-    assert_eq!(buffer.pixel_unchecked(999, 999), &PIXEL_TRANSPARENT);
+    assert_eq!(buffer.pixel_unchecked(0, 799), &PIXEL_TRANSPARENT);
+    assert_eq!(buffer.pixel_unchecked(1199, 0), &PIXEL_TRANSPARENT);
+    assert_eq!(buffer.pixel_unchecked(1199, 799), &PIXEL_TRANSPARENT);
 
     // eye's
-    assert_eq!(buffer.pixel_unchecked(500, 450), &PIXEL_WHITE);
-    assert_eq!(buffer.pixel_unchecked(500, 500), &PIXEL_BLACK);
-    assert_eq!(buffer.pixel_unchecked(700, 450), &PIXEL_WHITE);
-    assert_eq!(buffer.pixel_unchecked(700, 500), &PIXEL_BLACK);
+    assert_eq!(buffer.pixel_unchecked(495, 435), &PIXEL_WHITE);
+    assert_eq!(buffer.pixel_unchecked(520, 500), &PIXEL_BLACK);
+    assert_eq!(buffer.pixel_unchecked(700, 430), &PIXEL_WHITE);
+    assert_eq!(buffer.pixel_unchecked(720, 500), &PIXEL_BLACK);
 
-    // body
+    // body with gradient
     assert_eq!(
-        buffer.pixel_unchecked(200, 300),
-        &Pixel::new(245, 117, 0, 255)
+        buffer.pixel_unchecked(600, 100),
+        &Pixel::new(244, 141, 0, 255)
     );
     assert_eq!(
-        buffer.pixel_unchecked(999, 300),
-        &Pixel::new(246, 107, 0, 255)
+        buffer.pixel_unchecked(300, 300),
+        &Pixel::new(245, 116, 0, 255)
     );
 
     // count pixels with alpha
     let pixels_with_alpha = buffer.into_iter().filter(|&item| item[3] > 0).count();
-    assert_eq!(pixels_with_alpha, 473_746);
+    assert_eq!(pixels_with_alpha, 505_456);
     assert!(pixels_with_alpha < composition.pixels());
 }
- */
 
 #[test]
-fn todo() {
-    // TODO
+fn exported_svg_renders_the_same() {
+    let mut composition = Composition::builder()
+        .resolution(Resolution::Custom(1200, 800))
+        .framerate(1)
+        .duration(1)
+        .build();
+
+    let layer = Layer::from_file(
+        composition.resolution(),
+        env::current_dir()
+            .unwrap()
+            .as_path()
+            .join("./tests/ferris.svg"),
+    )
+    .unwrap();
+    composition.add_layer(layer);
+
+    let svg = composition.to_svg_string().unwrap();
+
+    let mut exported_composition = Composition::builder()
+        .resolution(Resolution::Custom(1200, 800))
+        .framerate(1)
+        .duration(1)
+        .build();
+
+    let mut layer = Layer::new(LayerType::Svg, exported_composition.resolution());
+    layer.object = Object::new(TypesLike::Svg(
+        SvgHolder::from_data(svg.as_bytes()).unwrap(),
+    ));
+    exported_composition.add_layer(layer);
+
+    let image_render = DummyRender::default();
+
+    let buffer = image_render.render_frame(&composition).unwrap();
+    let exported_buffer = image_render.render_frame(&exported_composition).unwrap();
+
+    assert_eq!(buffer, exported_buffer);
+}
+
+#[test]
+fn renders_image_file() {
+    let mut composition = Composition::builder()
+        .resolution(Resolution::Custom(4, 4))
+        .framerate(1)
+        .duration(1)
+        .build();
+
+    let path = env::temp_dir().join(format!("rusvid_image_layer_{}.png", std::process::id()));
+    let mut image = Plane::new(2, 2).unwrap();
+    image.fill(PIXEL_WHITE);
+    image.save_as_png(&path).unwrap();
+
+    let mut layer =
+        Layer::from_image_file(composition.resolution(), Point::new(-1.0, 1.0), &path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let id = layer.object.id().to_string();
+    layer
+        .object
+        .transform_by_id(id, &Transform::Move(Point::new(2.0, 0.0)))
+        .unwrap();
+    composition.add_layer(layer);
+
+    let buffer = DummyRender::default().render_frame(&composition).unwrap();
+
+    assert_eq!(buffer.pixel_unchecked(0, 1), &PIXEL_TRANSPARENT);
+    assert_eq!(buffer.pixel_unchecked(1, 1), &PIXEL_WHITE);
+    assert_eq!(buffer.pixel_unchecked(2, 2), &PIXEL_WHITE);
+    assert_eq!(buffer.pixel_unchecked(3, 2), &PIXEL_TRANSPARENT);
+    assert_eq!(buffer.pixel_unchecked(1, 0), &PIXEL_TRANSPARENT);
+}
+
+#[test]
+fn renders_text() {
+    let mut composition = Composition::builder()
+        .resolution(Resolution::Custom(200, 100))
+        .framerate(1)
+        .duration(1)
+        .build();
+
+    let font = Font::from_file(
+        env::current_dir()
+            .unwrap()
+            .as_path()
+            .join("../crates/core/tests/data/fonts/DejaVuSansMono.ttf"),
+    )
+    .unwrap();
+    let text = TextHolder::new_with_id(
+        "text",
+        Point::new(10.0, 10.0),
+        "I I",
+        &font,
+        TextOptions {
+            font_size: 50.0,
+            ..TextOptions::default()
+        },
+    );
+    let mut layer = Layer::from_text(composition.resolution(), text);
+    layer
+        .object
+        .transform_by_id("text_word_1", &Transform::Move(Point::new(0.0, 40.0)))
+        .unwrap();
+    composition.add_layer(layer);
+
+    let buffer = DummyRender::default().render_frame(&composition).unwrap();
+
+    // the vertical bar of the first `I` is in the middle of its advance
+    assert_eq!(buffer.pixel_unchecked(25, 40), &PIXEL_BLACK);
+    assert_eq!(buffer.pixel_unchecked(5, 40), &PIXEL_TRANSPARENT);
+    // the second `I` has been moved down
+    assert_eq!(buffer.pixel_unchecked(85, 30), &PIXEL_TRANSPARENT);
+    assert_eq!(buffer.pixel_unchecked(85, 80), &PIXEL_BLACK);
 }
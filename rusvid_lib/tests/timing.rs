@@ -0,0 +1,77 @@
+use rusvid_core::pixel::Pixel;
+use rusvid_core::point::Point;
+use rusvid_lib::animation::Range;
+use rusvid_lib::prelude::*;
+
+mod dummy;
+
+use dummy::{add_fading_rect, add_rect, alpha_at};
+
+fn composition() -> Composition {
+    let mut composition = Composition::builder()
+        .resolution(Resolution::Custom(10, 10))
+        .framerate(10)
+        .duration(1)
+        .build();
+    add_fading_rect(&mut composition);
+
+    composition
+}
+
+#[test]
+fn in_and_out_point() {
+    let mut composition = composition();
+    composition.get_layers_mut()[0].set_in_out(2..4);
+
+    assert_eq!(alpha_at(&mut composition, 1, 5, 5), 0);
+    assert_eq!(alpha_at(&mut composition, 2, 5, 5), 128);
+    assert_eq!(alpha_at(&mut composition, 3, 5, 5), 191);
+    assert_eq!(alpha_at(&mut composition, 4, 5, 5), 0);
+
+    assert!(!composition.to_svg_string().unwrap().contains("path"));
+}
+
+#[test]
+fn in_and_out_point_in_time() {
+    let mut composition = composition();
+    composition.get_layers_mut()[0].set_in_out(Range::seconds(0.2, 0.4));
+
+    assert_eq!(alpha_at(&mut composition, 1, 5, 5), 0);
+    assert_eq!(alpha_at(&mut composition, 2, 5, 5), 128);
+    assert_eq!(alpha_at(&mut composition, 4, 5, 5), 0);
+}
+
+#[test]
+fn time_offset() {
+    let mut composition = composition();
+    composition.get_layers_mut()[0].set_time_offset(2);
+
+    assert_eq!(alpha_at(&mut composition, 2, 5, 5), 0);
+    assert_eq!(alpha_at(&mut composition, 4, 5, 5), 128);
+    assert_eq!(alpha_at(&mut composition, 6, 5, 5), 255);
+}
+
+#[test]
+fn speed() {
+    let mut composition = composition();
+    composition.get_layers_mut()[0].set_speed(2.0);
+
+    assert_eq!(alpha_at(&mut composition, 0, 5, 5), 0);
+    assert_eq!(alpha_at(&mut composition, 1, 5, 5), 128);
+    assert_eq!(alpha_at(&mut composition, 2, 5, 5), 255);
+}
+
+#[test]
+fn inactive_matte_hides_layer() {
+    let mut composition = composition();
+    let matte_id = composition.get_layers()[0].object.id().to_string();
+    composition.get_layers_mut()[0].set_in_out(5..10);
+
+    let blue = Pixel::new(0, 0, 255, 255);
+    add_rect(&mut composition, Point::ZERO, Point::new(10.0, 10.0), blue)
+        .0
+        .set_mask(Some(Mask::layer(matte_id, MatteMode::Alpha)));
+
+    assert_eq!(alpha_at(&mut composition, 0, 5, 5), 0);
+    assert_eq!(alpha_at(&mut composition, 5, 5, 5), 255);
+}